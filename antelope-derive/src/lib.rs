@@ -0,0 +1,485 @@
+//! Derive macros for the [`antelope`](https://crates.io/crates/antelope) crate.
+//!
+//! Enable them with the `derive` feature of `antelope` rather than depending on this crate directly.
+//!
+//! - `#[derive(Pack, Unpack)]` serializes struct fields in declaration order. Enums are written as
+//!   a `varuint32` variant index followed by the variant fields, like `std::variant`.
+//! - `#[derive(UnpackBorrowed)]` does the same for structs and enums with a lifetime parameter,
+//!   whose fields may borrow from the input, e.g. `&'a str`, `&'a [u8]` or `VecView<'a, T>`.
+//! - `#[derive(AbiType)]` describes a struct as an ABI struct and an enum of single-field
+//!   variants as an ABI variant.
+//!
+//! Attributes:
+//!
+//! - `#[antelope(name = "...")]` on a struct or enum sets its ABI type name, which defaults to
+//!   the type name in snake case.
+//! - `#[antelope(binary_extension)]` on an `Option<T>` field marks it as a binary extension (`T$`):
+//!   it is omitted when `None` and defaults to `None` when the data ends before it. Binary
+//!   extensions must be the last fields of a struct. A later extension can only be `Some` if
+//!   every earlier one is, otherwise the derived `pack` panics.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Generics, LitStr, Member, Path,
+    PathArguments, Type,
+};
+
+/// Derives `antelope::Pack`
+///
+/// # Panics
+///
+/// The derived `pack` panics if a `#[antelope(binary_extension)]` field is `Some` after one that
+/// is `None`. Such data can't be decoded, as the absent extension ends the struct, and `nodeos`
+/// rejects it.
+#[proc_macro_derive(Pack, attributes(antelope))]
+pub fn derive_pack(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_pack(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derives `antelope::Unpack`
+#[proc_macro_derive(Unpack, attributes(antelope))]
+pub fn derive_unpack(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_unpack(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derives `antelope::UnpackBorrowed`
+#[proc_macro_derive(UnpackBorrowed, attributes(antelope))]
+pub fn derive_unpack_borrowed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_unpack_borrowed(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derives `antelope::abi_type::AbiType`
+#[proc_macro_derive(AbiType, attributes(antelope))]
+pub fn derive_abi_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_abi_type(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Options set with `#[antelope(...)]` on a struct or enum
+#[derive(Default)]
+struct ContainerAttrs {
+    name: Option<String>,
+}
+
+/// Options set with `#[antelope(...)]` on a field
+#[derive(Default)]
+struct FieldAttrs {
+    binary_extension: bool,
+}
+
+fn container_attrs(attrs: &[Attribute]) -> syn::Result<ContainerAttrs> {
+    let mut result = ContainerAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("antelope")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                result.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unknown antelope attribute"))
+            }
+        })?;
+    }
+    Ok(result)
+}
+
+fn field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut result = FieldAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("antelope")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("binary_extension") {
+                result.binary_extension = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown antelope attribute"))
+            }
+        })?;
+    }
+    Ok(result)
+}
+
+/// A field of a struct or enum variant
+struct Field<'a> {
+    member: Member,
+    ty: &'a Type,
+    /// The `T` of an `Option<T>` binary extension field
+    extension: Option<&'a Type>,
+}
+
+/// Returns `T` if `ty` is `Option<T>`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+fn parse_fields(fields: &Fields) -> syn::Result<Vec<Field<'_>>> {
+    let mut result: Vec<Field> = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        };
+        let extension = if field_attrs(&field.attrs)?.binary_extension {
+            let inner =
+                option_inner(&field.ty).ok_or_else(|| Error::new_spanned(&field.ty, "binary extension fields must be `Option<T>`"))?;
+            Some(inner)
+        } else {
+            if result.iter().any(|f| f.extension.is_some()) {
+                return Err(Error::new_spanned(
+                    field,
+                    "only binary extension fields may follow a binary extension",
+                ));
+            }
+            None
+        };
+        result.push(Field {
+            member,
+            ty: &field.ty,
+            extension,
+        });
+    }
+    Ok(result)
+}
+
+/// Adds `bound` to every type parameter
+fn add_bounds(generics: &Generics, bound: Path) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+/// Returns the bindings used to destructure the fields of an enum variant
+fn bindings(fields: &[Field]) -> Vec<proc_macro2::Ident> {
+    (0..fields.len()).map(|i| format_ident!("__field{}", i)).collect()
+}
+
+/// Returns the pattern destructuring an enum variant into `bindings`
+fn variant_pattern(fields: &Fields, bindings: &[proc_macro2::Ident]) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote!({ #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#bindings),* )),
+        Fields::Unit => quote!(),
+    }
+}
+
+/// Packs `values` in order, panicking if a binary extension follows one that is `None`
+fn pack_fields(fields: &[Field], values: &[TokenStream2]) -> TokenStream2 {
+    let regular = fields
+        .iter()
+        .zip(values)
+        .filter(|(f, _)| f.extension.is_none())
+        .map(|(_, value)| quote!(::antelope::Pack::pack(#value, out);));
+    let extensions: Vec<_> = fields
+        .iter()
+        .zip(values)
+        .filter(|(f, _)| f.extension.is_some())
+        .map(|(_, value)| value)
+        .collect();
+    let extensions = if extensions.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            let mut __present = true;
+            #(::antelope::pack_binary_extension(#extensions, &mut __present, out);)*
+        }
+    };
+    quote!(#(#regular)* #extensions)
+}
+
+/// Builds a struct or variant `path` from fields read with `unpack`
+fn unpack_fields(path: TokenStream2, fields: &Fields, parsed: &[Field], unpack: &TokenStream2) -> TokenStream2 {
+    let values = parsed.iter().map(|f| match f.extension {
+        Some(_) => quote!(::antelope::unpack_binary_extension(data)?),
+        None => quote!(#unpack(data)?),
+    });
+    match fields {
+        Fields::Named(_) => {
+            let members = parsed.iter().map(|f| &f.member);
+            quote!(#path { #(#members: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path( #(#values),* )),
+        Fields::Unit => path,
+    }
+}
+
+fn no_extensions(fields: &[Field], span: &impl quote::ToTokens) -> syn::Result<()> {
+    match fields.iter().any(|f| f.extension.is_some()) {
+        true => Err(Error::new_spanned(span, "binary extensions are only supported on struct fields")),
+        false => Ok(()),
+    }
+}
+
+fn expand_pack(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, parse_quote!(::antelope::Pack));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = parse_fields(&data.fields)?;
+            let values: Vec<_> = fields
+                .iter()
+                .map(|f| {
+                    let member = &f.member;
+                    quote!(&self.#member)
+                })
+                .collect();
+            pack_fields(&fields, &values)
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let fields = parse_fields(&variant.fields)?;
+                    no_extensions(&fields, variant)?;
+                    let bindings = bindings(&fields);
+                    let pattern = variant_pattern(&variant.fields, &bindings);
+                    let values: Vec<_> = bindings.iter().map(|b| quote!(#b)).collect();
+                    let pack = pack_fields(&fields, &values);
+                    let index = index as u32;
+                    let name = &variant.ident;
+                    Ok(quote! {
+                        Self::#name #pattern => {
+                            ::antelope::pack_varuint32(#index, out);
+                            #pack
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!(match self { #(#arms)* })
+        }
+        Data::Union(_) => return Err(Error::new_spanned(input, "Pack cannot be derived for unions")),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::antelope::Pack for #ident #ty_generics #where_clause {
+            fn pack(&self, out: &mut ::std::vec::Vec<u8>) {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the body of `unpack` or `unpack_borrowed`, reading fields with `unpack`
+fn unpack_body(input: &DeriveInput, unpack: TokenStream2) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    match &input.data {
+        Data::Struct(data) => {
+            let fields = parse_fields(&data.fields)?;
+            let value = unpack_fields(quote!(Self), &data.fields, &fields, &unpack);
+            Ok(quote!(::std::result::Result::Ok(#value)))
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let fields = parse_fields(&variant.fields)?;
+                    no_extensions(&fields, variant)?;
+                    let name = &variant.ident;
+                    let value = unpack_fields(quote!(Self::#name), &variant.fields, &fields, &unpack);
+                    let index = index as u32;
+                    Ok(quote!(#index => ::std::result::Result::Ok(#value),))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let name = ident.to_string();
+            Ok(quote! {
+                match ::antelope::unpack_varuint32(data)? {
+                    #(#arms)*
+                    index => ::std::result::Result::Err(::antelope::UnpackError::BadData(
+                        ::std::format!("unknown variant {} of {}", index, #name)
+                    )),
+                }
+            })
+        }
+        Data::Union(_) => Err(Error::new_spanned(input, "Unpack cannot be derived for unions")),
+    }
+}
+
+fn expand_unpack(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, parse_quote!(::antelope::Unpack));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = unpack_body(input, quote!(::antelope::Unpack::unpack))?;
+
+    Ok(quote! {
+        impl #impl_generics ::antelope::Unpack for #ident #ty_generics #where_clause {
+            fn unpack(data: &mut &[u8]) -> ::std::result::Result<Self, ::antelope::UnpackError> {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_unpack_borrowed(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    // types without a lifetime implement `UnpackBorrowed` through `Unpack`
+    let mut lifetimes = input.generics.lifetimes();
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(param), None) => param.lifetime.clone(),
+        _ => {
+            return Err(Error::new_spanned(
+                &input.generics,
+                "UnpackBorrowed requires exactly one lifetime parameter, derive Unpack for owned types",
+            ))
+        }
+    };
+    let generics = add_bounds(&input.generics, parse_quote!(::antelope::UnpackBorrowed<#lifetime>));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = unpack_body(input, quote!(::antelope::UnpackBorrowed::unpack_borrowed))?;
+
+    Ok(quote! {
+        impl #impl_generics ::antelope::UnpackBorrowed<#lifetime> for #ident #ty_generics #where_clause {
+            fn unpack_borrowed(data: &mut &#lifetime [u8]) -> ::std::result::Result<Self, ::antelope::UnpackError> {
+                #body
+            }
+        }
+    })
+}
+
+/// Converts a Rust type name to snake case, e.g. `TransferArgs` to `transfer_args`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
+
+fn expand_abi_type(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, parse_quote!(::antelope::abi_type::AbiType));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = container_attrs(&input.attrs)?
+        .name
+        .unwrap_or_else(|| snake_case(&ident.to_string()));
+
+    let add_definitions = match &input.data {
+        Data::Struct(data) => {
+            if let Fields::Unnamed(_) = data.fields {
+                return Err(Error::new_spanned(input, "AbiType requires named fields"));
+            }
+            let fields = parse_fields(&data.fields)?;
+            let field_defs = fields.iter().map(|f| {
+                let field_name = match &f.member {
+                    Member::Named(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                };
+                let field_type = match f.extension {
+                    Some(inner) => quote!(::std::format!("{}$", <#inner as ::antelope::abi_type::AbiType>::abi_type())),
+                    None => {
+                        let ty = f.ty;
+                        quote!(<#ty as ::antelope::abi_type::AbiType>::abi_type())
+                    }
+                };
+                quote! {
+                    ::antelope::abi::FieldDef {
+                        name: ::std::string::String::from(#field_name),
+                        r#type: #field_type,
+                    }
+                }
+            });
+            let dependencies = fields.iter().map(|f| f.extension.unwrap_or(f.ty));
+            quote! {
+                if abi.struct_def(#name).is_some() {
+                    return;
+                }
+                abi.structs.push(::antelope::abi::StructDef {
+                    name: ::std::string::String::from(#name),
+                    base: ::std::string::String::new(),
+                    fields: ::std::vec![#(#field_defs),*],
+                });
+                #(<#dependencies as ::antelope::abi_type::AbiType>::add_definitions(abi);)*
+            }
+        }
+        Data::Enum(data) => {
+            let types = data
+                .variants
+                .iter()
+                .map(|variant| match &variant.fields {
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(&fields.unnamed[0].ty),
+                    _ => Err(Error::new_spanned(
+                        variant,
+                        "AbiType requires variants with exactly one unnamed field",
+                    )),
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                if abi.variant_def(#name).is_some() {
+                    return;
+                }
+                abi.variants.push(::antelope::abi::VariantDef {
+                    name: ::std::string::String::from(#name),
+                    types: ::std::vec![#(<#types as ::antelope::abi_type::AbiType>::abi_type()),*],
+                });
+                #(<#types as ::antelope::abi_type::AbiType>::add_definitions(abi);)*
+            }
+        }
+        Data::Union(_) => return Err(Error::new(Span::call_site(), "AbiType cannot be derived for unions")),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::antelope::abi_type::AbiType for #ident #ty_generics #where_clause {
+            fn abi_type() -> ::std::string::String {
+                ::std::string::String::from(#name)
+            }
+
+            fn add_definitions(abi: &mut ::antelope::abi::AbiDef) {
+                #add_definitions
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("Transfer"), "transfer");
+        assert_eq!(snake_case("TransferArgs"), "transfer_args");
+        assert_eq!(snake_case("HTTPRequest"), "http_request");
+        assert_eq!(snake_case("Account2Name"), "account2_name");
+    }
+
+    #[test]
+    fn test_option_inner() {
+        let ty: Type = parse_quote!(Option<u32>);
+        assert!(option_inner(&ty).is_some());
+        let ty: Type = parse_quote!(std::option::Option<Vec<u8>>);
+        assert!(option_inner(&ty).is_some());
+        let ty: Type = parse_quote!(Vec<u8>);
+        assert!(option_inner(&ty).is_none());
+    }
+}
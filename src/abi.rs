@@ -0,0 +1,615 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::serializer::impl_pack_struct;
+use crate::{AbiError, Name, Pack, Unpack, UnpackError};
+
+/// The ABI version prefix accepted by `nodeos`
+pub const ABI_VERSION_PREFIX: &str = "eosio::abi/1.";
+
+/// The latest ABI version supported
+pub const ABI_VERSION: &str = "eosio::abi/1.3";
+
+/// The `TypeDef` struct represents a type alias
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/abi_def.hpp>
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TypeDef {
+    pub new_type_name: String,
+    pub r#type: String,
+}
+
+/// The `FieldDef` struct represents a single field of a struct
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FieldDef {
+    pub name: String,
+    pub r#type: String,
+}
+
+/// The `StructDef` struct represents a struct, optionally extending a `base` struct
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StructDef {
+    pub name: String,
+    #[serde(default)]
+    pub base: String,
+    #[serde(default)]
+    pub fields: Vec<FieldDef>,
+}
+
+/// The `ActionDef` struct maps an action name to the type of its data
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ActionDef {
+    pub name: Name,
+    pub r#type: String,
+    #[serde(default)]
+    pub ricardian_contract: String,
+}
+
+/// The `TableDef` struct maps a table name to the type of its rows
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TableDef {
+    pub name: Name,
+    #[serde(default)]
+    pub index_type: String,
+    #[serde(default)]
+    pub key_names: Vec<String>,
+    #[serde(default)]
+    pub key_types: Vec<String>,
+    pub r#type: String,
+}
+
+/// The `ClausePair` struct represents a ricardian clause
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ClausePair {
+    pub id: String,
+    pub body: String,
+}
+
+/// The `ErrorMessage` struct maps an error code to a message
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ErrorMessage {
+    #[serde(deserialize_with = "crate::serde_helpers::deserialize_number")]
+    pub error_code: u64,
+    pub error_msg: String,
+}
+
+/// The `AbiExtension` struct is a tagged binary extension of the ABI
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AbiExtension(pub u16, #[serde(with = "crate::serde_helpers::hex")] pub Vec<u8>);
+
+/// The `VariantDef` struct represents a tagged union of types
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct VariantDef {
+    pub name: String,
+    pub types: Vec<String>,
+}
+
+/// The `ActionResultDef` struct maps an action name to the type of its return value
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ActionResultDef {
+    pub name: Name,
+    pub result_type: String,
+}
+
+/// The `PrimaryKeyIndexDef` struct represents the primary index of a kv table
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PrimaryKeyIndexDef {
+    pub name: Name,
+    pub r#type: String,
+}
+
+/// The `SecondaryIndexDef` struct represents a secondary index of a kv table
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SecondaryIndexDef {
+    pub r#type: String,
+}
+
+/// The `KvTableEntryDef` struct represents a kv table
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct KvTableEntryDef {
+    pub r#type: String,
+    pub primary_index: PrimaryKeyIndexDef,
+    #[serde(default)]
+    pub secondary_indices: BTreeMap<Name, SecondaryIndexDef>,
+}
+
+/// The `AbiDef` struct represents a contract ABI, versions `eosio::abi/1.0` through `eosio::abi/1.3`
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/abi_def.hpp>
+///
+/// # Examples
+///
+/// ```
+/// use antelope::abi::AbiDef;
+/// use antelope::Name;
+///
+/// let abi = AbiDef::from_json(r#"{
+///     "version": "eosio::abi/1.1",
+///     "structs": [{ "name": "hi", "base": "", "fields": [{ "name": "user", "type": "name" }] }],
+///     "actions": [{ "name": "hi", "type": "hi", "ricardian_contract": "" }]
+/// }"#).unwrap();
+/// assert_eq!("hi", abi.action(Name::from("hi")).unwrap().r#type);
+/// assert_eq!("user", abi.struct_def("hi").unwrap().fields[0].name);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiDef {
+    pub version: String,
+    #[serde(default)]
+    pub types: Vec<TypeDef>,
+    #[serde(default)]
+    pub structs: Vec<StructDef>,
+    #[serde(default)]
+    pub actions: Vec<ActionDef>,
+    #[serde(default)]
+    pub tables: Vec<TableDef>,
+    #[serde(default)]
+    pub ricardian_clauses: Vec<ClausePair>,
+    #[serde(default)]
+    pub error_messages: Vec<ErrorMessage>,
+    #[serde(default)]
+    pub abi_extensions: Vec<AbiExtension>,
+    #[serde(default)]
+    pub variants: Vec<VariantDef>,
+    #[serde(default)]
+    pub action_results: Vec<ActionResultDef>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub kv_tables: BTreeMap<Name, KvTableEntryDef>,
+}
+
+impl Default for AbiDef {
+    fn default() -> Self {
+        AbiDef {
+            version: ABI_VERSION.to_string(),
+            types: Vec::new(),
+            structs: Vec::new(),
+            actions: Vec::new(),
+            tables: Vec::new(),
+            ricardian_clauses: Vec::new(),
+            error_messages: Vec::new(),
+            abi_extensions: Vec::new(),
+            variants: Vec::new(),
+            action_results: Vec::new(),
+            kv_tables: BTreeMap::new(),
+        }
+    }
+}
+
+impl AbiDef {
+    /// Parses an ABI from its JSON representation, as returned by `get_abi`
+    pub fn from_json(json: &str) -> Result<Self, AbiError> {
+        let abi: AbiDef = serde_json::from_str(json).map_err(|e| AbiError::BadJson(e.to_string()))?;
+        abi.check_version()?;
+        Ok(abi)
+    }
+
+    /// Parses an ABI from its binary form, as carried by the `abi` field of `eosio::setabi`
+    pub fn from_binary(data: &[u8]) -> Result<Self, AbiError> {
+        let abi: AbiDef = crate::unpack(data)?;
+        abi.check_version()?;
+        Ok(abi)
+    }
+
+    /// Returns the JSON representation of the ABI
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("abi serialization failed")
+    }
+
+    /// Checks that the ABI version is `eosio::abi/1.x`
+    pub fn check_version(&self) -> Result<(), AbiError> {
+        match self.version.strip_prefix(ABI_VERSION_PREFIX).map(u32::from_str) {
+            Some(Ok(_)) => Ok(()),
+            _ => Err(AbiError::UnsupportedVersion(self.version.clone())),
+        }
+    }
+
+    /// Returns the type alias named `name`
+    pub fn type_def(&self, name: &str) -> Option<&TypeDef> {
+        self.types.iter().find(|t| t.new_type_name == name)
+    }
+
+    /// Returns the struct named `name`
+    pub fn struct_def(&self, name: &str) -> Option<&StructDef> {
+        self.structs.iter().find(|s| s.name == name)
+    }
+
+    /// Returns the variant named `name`
+    pub fn variant_def(&self, name: &str) -> Option<&VariantDef> {
+        self.variants.iter().find(|v| v.name == name)
+    }
+
+    /// Returns the action named `name`
+    pub fn action(&self, name: Name) -> Option<&ActionDef> {
+        self.actions.iter().find(|a| a.name == name)
+    }
+
+    /// Returns the table named `name`
+    pub fn table(&self, name: Name) -> Option<&TableDef> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+
+    /// Returns the return value definition of the action named `name`
+    pub fn action_result(&self, name: Name) -> Option<&ActionResultDef> {
+        self.action_results.iter().find(|r| r.name == name)
+    }
+
+    /// Returns the ricardian clause with the given `id`
+    pub fn ricardian_clause(&self, id: &str) -> Option<&ClausePair> {
+        self.ricardian_clauses.iter().find(|c| c.id == id)
+    }
+
+    /// Returns the message of the given error code
+    pub fn error_message(&self, error_code: u64) -> Option<&str> {
+        self.error_messages
+            .iter()
+            .find(|e| e.error_code == error_code)
+            .map(|e| e.error_msg.as_str())
+    }
+}
+
+impl_pack_struct!(TypeDef { new_type_name, r#type });
+impl_pack_struct!(FieldDef { name, r#type });
+impl_pack_struct!(StructDef { name, base, fields });
+impl_pack_struct!(ActionDef {
+    name,
+    r#type,
+    ricardian_contract
+});
+impl_pack_struct!(TableDef {
+    name,
+    index_type,
+    key_names,
+    key_types,
+    r#type
+});
+impl_pack_struct!(ClausePair { id, body });
+impl_pack_struct!(ErrorMessage { error_code, error_msg });
+impl_pack_struct!(VariantDef { name, types });
+impl_pack_struct!(ActionResultDef { name, result_type });
+impl_pack_struct!(PrimaryKeyIndexDef { name, r#type });
+impl_pack_struct!(SecondaryIndexDef { r#type });
+impl_pack_struct!(KvTableEntryDef {
+    r#type,
+    primary_index,
+    secondary_indices
+});
+
+impl Pack for AbiExtension {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.0.pack(out);
+        self.1.pack(out);
+    }
+}
+
+impl Unpack for AbiExtension {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(AbiExtension(u16::unpack(data)?, Vec::unpack(data)?))
+    }
+}
+
+impl Pack for AbiDef {
+    /**
+     * Packs the ABI in the binary form carried by `eosio::setabi`
+     *
+     * `variants` and `action_results` are always written, `kv_tables` only when present.
+     */
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.version.pack(out);
+        self.types.pack(out);
+        self.structs.pack(out);
+        self.actions.pack(out);
+        self.tables.pack(out);
+        self.ricardian_clauses.pack(out);
+        self.error_messages.pack(out);
+        self.abi_extensions.pack(out);
+        self.variants.pack(out);
+        self.action_results.pack(out);
+        if !self.kv_tables.is_empty() {
+            self.kv_tables.pack(out);
+        }
+    }
+}
+
+impl Unpack for AbiDef {
+    /**
+     * Unpacks the binary form of an ABI
+     *
+     * `variants`, `action_results` and `kv_tables` are binary extensions and may be missing.
+     */
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let mut abi = AbiDef {
+            version: String::unpack(data)?,
+            types: Vec::unpack(data)?,
+            structs: Vec::unpack(data)?,
+            actions: Vec::unpack(data)?,
+            tables: Vec::unpack(data)?,
+            ricardian_clauses: Vec::unpack(data)?,
+            error_messages: Vec::unpack(data)?,
+            abi_extensions: Vec::unpack(data)?,
+            ..Default::default()
+        };
+        if !data.is_empty() {
+            abi.variants = Vec::unpack(data)?;
+        }
+        if !data.is_empty() {
+            abi.action_results = Vec::unpack(data)?;
+        }
+        if !data.is_empty() {
+            abi.kv_tables = BTreeMap::unpack(data)?;
+        }
+        Ok(abi)
+    }
+}
+
+impl FromStr for AbiDef {
+    type Err = AbiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AbiDef::from_json(s)
+    }
+}
+
+/// The `GetAbiResult` struct is the response of the `/v1/chain/get_abi` endpoint
+///
+/// # Examples
+///
+/// ```
+/// use antelope::abi::GetAbiResult;
+///
+/// let result = GetAbiResult::from_json(r#"{"account_name":"eosio.null"}"#).unwrap();
+/// assert_eq!("eosio.null", result.account_name.to_string());
+/// assert!(result.abi.is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GetAbiResult {
+    pub account_name: Name,
+    #[serde(default)]
+    pub abi: Option<AbiDef>,
+}
+
+impl GetAbiResult {
+    /// Parses the JSON response of `get_abi`
+    pub fn from_json(json: &str) -> Result<Self, AbiError> {
+        let result: GetAbiResult = serde_json::from_str(json).map_err(|e| AbiError::BadJson(e.to_string()))?;
+        if let Some(abi) = &result.abi {
+            abi.check_version()?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::bytes::from_hex;
+
+    pub(crate) const TOKEN_ABI: &str = r#"{
+        "version": "eosio::abi/1.2",
+        "types": [],
+        "structs": [
+            { "name": "account", "base": "", "fields": [{ "name": "balance", "type": "asset" }] },
+            { "name": "close", "base": "", "fields": [{ "name": "owner", "type": "name" }, { "name": "symbol", "type": "symbol" }] },
+            { "name": "create", "base": "", "fields": [{ "name": "issuer", "type": "name" }, { "name": "maximum_supply", "type": "asset" }] },
+            {
+                "name": "currency_stats",
+                "base": "",
+                "fields": [{ "name": "supply", "type": "asset" }, { "name": "max_supply", "type": "asset" }, { "name": "issuer", "type": "name" }]
+            },
+            {
+                "name": "issue",
+                "base": "",
+                "fields": [{ "name": "to", "type": "name" }, { "name": "quantity", "type": "asset" }, { "name": "memo", "type": "string" }]
+            },
+            {
+                "name": "open",
+                "base": "",
+                "fields": [{ "name": "owner", "type": "name" }, { "name": "symbol", "type": "symbol" }, { "name": "ram_payer", "type": "name" }]
+            },
+            { "name": "retire", "base": "", "fields": [{ "name": "quantity", "type": "asset" }, { "name": "memo", "type": "string" }] },
+            {
+                "name": "transfer",
+                "base": "",
+                "fields": [
+                    { "name": "from", "type": "name" },
+                    { "name": "to", "type": "name" },
+                    { "name": "quantity", "type": "asset" },
+                    { "name": "memo", "type": "string" }
+                ]
+            }
+        ],
+        "actions": [
+            { "name": "close", "type": "close", "ricardian_contract": "" },
+            { "name": "create", "type": "create", "ricardian_contract": "" },
+            { "name": "issue", "type": "issue", "ricardian_contract": "" },
+            { "name": "open", "type": "open", "ricardian_contract": "" },
+            { "name": "retire", "type": "retire", "ricardian_contract": "" },
+            { "name": "transfer", "type": "transfer", "ricardian_contract": "" }
+        ],
+        "tables": [
+            { "name": "accounts", "type": "account", "index_type": "i64", "key_names": [], "key_types": [] },
+            { "name": "stat", "type": "currency_stats", "index_type": "i64", "key_names": [], "key_types": [] }
+        ],
+        "ricardian_clauses": [],
+        "variants": [],
+        "action_results": []
+    }"#;
+
+    pub(crate) const FULL_ABI: &str = r#"{
+        "version": "eosio::abi/1.3",
+        "types": [{ "new_type_name": "account_name", "type": "name" }],
+        "structs": [
+            { "name": "base", "base": "", "fields": [{ "name": "owner", "type": "account_name" }] },
+            { "name": "derived", "base": "base", "fields": [{ "name": "values", "type": "uint32[]" }, { "name": "note", "type": "string?" }] },
+            { "name": "setvalue", "base": "", "fields": [{ "name": "value", "type": "value_type" }, { "name": "extra", "type": "uint8$" }] }
+        ],
+        "actions": [{ "name": "setvalue", "type": "setvalue", "ricardian_contract": "---\ntitle: Set Value\n---" }],
+        "tables": [{ "name": "values", "type": "derived", "index_type": "i64", "key_names": ["owner"], "key_types": ["uint64"] }],
+        "ricardian_clauses": [{ "id": "Data Storage", "body": "Stores a value." }],
+        "error_messages": [{ "error_code": 10, "error_msg": "value too large" }, { "error_code": "18446744073709551615", "error_msg": "max" }],
+        "abi_extensions": [[1, "0a0b"]],
+        "variants": [{ "name": "value_type", "types": ["uint64", "string", "derived"] }],
+        "action_results": [{ "name": "setvalue", "result_type": "uint64" }],
+        "kv_tables": {
+            "kvvalues": {
+                "type": "derived",
+                "primary_index": { "name": "owner", "type": "name" },
+                "secondary_indices": { "byvalue": { "type": "uint64" } }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_token_abi() {
+        let abi = AbiDef::from_json(TOKEN_ABI).unwrap();
+        assert_eq!(abi.version, "eosio::abi/1.2");
+        assert_eq!(abi.structs.len(), 8);
+        assert_eq!(abi.actions.len(), 6);
+        assert_eq!(abi.action(Name::from("transfer")).unwrap().r#type, "transfer");
+        assert_eq!(abi.table(Name::from("accounts")).unwrap().r#type, "account");
+        assert_eq!(abi.table(Name::from("stat")).unwrap().index_type, "i64");
+        let transfer = abi.struct_def("transfer").unwrap();
+        let fields: Vec<(&str, &str)> = transfer.fields.iter().map(|f| (f.name.as_str(), f.r#type.as_str())).collect();
+        assert_eq!(
+            fields,
+            [("from", "name"), ("to", "name"), ("quantity", "asset"), ("memo", "string")]
+        );
+        assert!(abi.action(Name::from("unknown")).is_none());
+        assert!(abi.kv_tables.is_empty());
+    }
+
+    #[test]
+    fn test_full_abi() {
+        let abi = AbiDef::from_json(FULL_ABI).unwrap();
+        assert_eq!(abi.type_def("account_name").unwrap().r#type, "name");
+        assert_eq!(abi.struct_def("derived").unwrap().base, "base");
+        assert_eq!(abi.variant_def("value_type").unwrap().types, ["uint64", "string", "derived"]);
+        assert_eq!(abi.action_result(Name::from("setvalue")).unwrap().result_type, "uint64");
+        assert_eq!(abi.ricardian_clause("Data Storage").unwrap().body, "Stores a value.");
+        assert_eq!(
+            abi.action(Name::from("setvalue")).unwrap().ricardian_contract,
+            "---\ntitle: Set Value\n---"
+        );
+        assert_eq!(abi.error_message(10), Some("value too large"));
+        assert_eq!(abi.error_message(u64::MAX), Some("max"));
+        assert_eq!(abi.error_message(11), None);
+        assert_eq!(abi.abi_extensions, [AbiExtension(1, vec![0x0a, 0x0b])]);
+        assert_eq!(abi.table(Name::from("values")).unwrap().key_names, ["owner"]);
+
+        let kv_table = &abi.kv_tables[&Name::from("kvvalues")];
+        assert_eq!(kv_table.r#type, "derived");
+        assert_eq!(kv_table.primary_index.name, Name::from("owner"));
+        assert_eq!(kv_table.secondary_indices[&Name::from("byvalue")].r#type, "uint64");
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        for json in [TOKEN_ABI, FULL_ABI] {
+            let abi = AbiDef::from_json(json).unwrap();
+            assert_eq!(AbiDef::from_json(&abi.to_json()).unwrap(), abi);
+        }
+        assert!(!AbiDef::from_json(TOKEN_ABI).unwrap().to_json().contains("kv_tables"));
+    }
+
+    #[test]
+    fn test_minimal_abi() {
+        let abi: AbiDef = r#"{"version":"eosio::abi/1.0"}"#.parse().unwrap();
+        assert_eq!(
+            abi,
+            AbiDef {
+                version: "eosio::abi/1.0".to_string(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(AbiDef::default().version, ABI_VERSION);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            AbiDef::from_json(r#"{"version":"eosio::abi/2.0"}"#),
+            Err(AbiError::UnsupportedVersion("eosio::abi/2.0".to_string()))
+        );
+        assert_eq!(
+            AbiDef::from_json(r#"{"version":"eosio::abi/1.x"}"#),
+            Err(AbiError::UnsupportedVersion("eosio::abi/1.x".to_string()))
+        );
+        assert!(matches!(AbiDef::from_json(r#"{"types":[]}"#), Err(AbiError::BadJson(_))));
+        assert!(matches!(
+            AbiDef::from_json(r#"{"version":"eosio::abi/1.1","actions":[{"name":"INVALID","type":"t"}]}"#),
+            Err(AbiError::BadJson(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_abi_result() {
+        let json = format!(r#"{{"account_name":"eosio.token","abi":{}}}"#, TOKEN_ABI);
+        let result = GetAbiResult::from_json(&json).unwrap();
+        assert_eq!(result.account_name, Name::from("eosio.token"));
+        assert_eq!(result.abi, Some(AbiDef::from_json(TOKEN_ABI).unwrap()));
+    }
+
+    #[test]
+    fn test_binary() {
+        let abi = AbiDef::from_json(
+            r#"{
+                "version": "eosio::abi/1.1",
+                "structs": [{ "name": "hi", "base": "", "fields": [{ "name": "user", "type": "name" }] }],
+                "actions": [{ "name": "hi", "type": "hi", "ricardian_contract": "" }]
+            }"#,
+        )
+        .unwrap();
+        let hex = concat!(
+            "0e656f73696f3a3a6162692f312e31",   // version
+            "00",                               // types
+            "0102686900010475736572046e616d65", // structs
+            "01000000000000806b02686900",       // actions
+            "0000000000",                       // tables, ricardian_clauses, error_messages, abi_extensions, variants
+            "00",                               // action_results
+        );
+        assert_eq!(abi.packed(), from_hex(hex).unwrap());
+        assert_eq!(AbiDef::from_binary(&from_hex(hex).unwrap()).unwrap(), abi);
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        for json in [TOKEN_ABI, FULL_ABI] {
+            let abi = AbiDef::from_json(json).unwrap();
+            assert_eq!(AbiDef::from_binary(&abi.packed()).unwrap(), abi);
+        }
+    }
+
+    #[test]
+    fn test_binary_without_extensions() {
+        let hex = "0e656f73696f3a3a6162692f312e3000000000000000";
+        let abi = AbiDef::from_binary(&from_hex(hex).unwrap()).unwrap();
+        assert_eq!(
+            abi,
+            AbiDef {
+                version: "eosio::abi/1.0".to_string(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(abi.packed(), from_hex(&format!("{}0000", hex)).unwrap());
+
+        assert_eq!(
+            AbiDef::from_binary(&from_hex(&hex[..20]).unwrap()),
+            Err(AbiError::BadBinary(UnpackError::UnexpectedEof))
+        );
+        assert_eq!(
+            AbiDef::from_binary(&from_hex("0e656f73696f3a3a6162692f322e3000000000000000").unwrap()),
+            Err(AbiError::UnsupportedVersion("eosio::abi/2.0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_setabi() {
+        // eosio::setabi { account: name, abi: bytes }
+        let abi = AbiDef::from_json(TOKEN_ABI).unwrap();
+        let setabi = (Name::from("eosio.token"), abi.packed()).packed();
+
+        let (account, bytes) = crate::unpack::<(Name, Vec<u8>)>(&setabi).unwrap();
+        assert_eq!(account, Name::from("eosio.token"));
+        assert_eq!(AbiDef::from_binary(&bytes).unwrap(), abi);
+    }
+}
@@ -0,0 +1,775 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::abi::{AbiDef, StructDef, VariantDef};
+use crate::{
+    pack_varuint32, unpack_varuint32, AbiError, Asset, BlockTimestamp, Bytes, Checksum160, Checksum256, Checksum512, ExtendedAsset,
+    Float128, Name, Pack, PublicKey, Signature, Symbol, SymbolCode, TimePoint, TimePointSec, Unpack, VarInt32, VarUint32,
+};
+
+/// The maximum nesting depth of types, matching `nodeos`
+pub const MAX_RECURSION_DEPTH: usize = 32;
+
+type PackFn = fn(&Value, &mut Vec<u8>) -> Result<(), AbiError>;
+type UnpackFn = fn(&mut &[u8]) -> Result<Value, AbiError>;
+
+fn pack_serde<T: DeserializeOwned + Pack>(value: &Value, out: &mut Vec<u8>) -> Result<(), AbiError> {
+    let value = T::deserialize(value).map_err(|e| AbiError::BadValue(e.to_string()))?;
+    value.pack(out);
+    Ok(())
+}
+
+fn unpack_serde<T: Serialize + Unpack>(data: &mut &[u8]) -> Result<Value, AbiError> {
+    let value = T::unpack(data)?;
+    serde_json::to_value(value).map_err(|e| AbiError::BadValue(e.to_string()))
+}
+
+fn pack_int<T>(value: &Value, out: &mut Vec<u8>) -> Result<(), AbiError>
+where
+    T: Pack + std::str::FromStr + TryFrom<i64> + TryFrom<u64>,
+    T::Err: std::fmt::Display,
+{
+    let value: T = crate::serde_helpers::deserialize_number(value).map_err(|e| AbiError::BadValue(e.to_string()))?;
+    value.pack(out);
+    Ok(())
+}
+
+/// Packs an integer read from JSON through a wrapper type, e.g. `u32` as `VarUint32`
+fn pack_int_as<T, P>(value: &Value, out: &mut Vec<u8>) -> Result<(), AbiError>
+where
+    T: std::str::FromStr + TryFrom<i64> + TryFrom<u64> + Into<P>,
+    T::Err: std::fmt::Display,
+    P: Pack,
+{
+    let value: T = crate::serde_helpers::deserialize_number(value).map_err(|e| AbiError::BadValue(e.to_string()))?;
+    value.into().pack(out);
+    Ok(())
+}
+
+fn unpack_value<T: Unpack + Into<Value>>(data: &mut &[u8]) -> Result<Value, AbiError> {
+    Ok(T::unpack(data)?.into())
+}
+
+/// 64-bit integers outside of the 32-bit range are written as strings, like `nodeos` does
+fn unpack_int64(data: &mut &[u8]) -> Result<Value, AbiError> {
+    let value = i64::unpack(data)?;
+    Ok(match i32::try_from(value) {
+        Ok(_) => Value::from(value),
+        Err(_) => Value::String(value.to_string()),
+    })
+}
+
+fn unpack_uint64(data: &mut &[u8]) -> Result<Value, AbiError> {
+    let value = u64::unpack(data)?;
+    Ok(match u32::try_from(value) {
+        Ok(_) => Value::from(value),
+        Err(_) => Value::String(value.to_string()),
+    })
+}
+
+/// 128-bit integers are always written as strings
+fn unpack_int128<T: Unpack + ToString>(data: &mut &[u8]) -> Result<Value, AbiError> {
+    Ok(Value::String(T::unpack(data)?.to_string()))
+}
+
+fn parse_float(value: &Value) -> Result<f64, AbiError> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| AbiError::BadValue(format!("expected float, got {}", value)))
+}
+
+fn pack_float32(value: &Value, out: &mut Vec<u8>) -> Result<(), AbiError> {
+    (parse_float(value)? as f32).pack(out);
+    Ok(())
+}
+
+fn pack_float64(value: &Value, out: &mut Vec<u8>) -> Result<(), AbiError> {
+    parse_float(value)?.pack(out);
+    Ok(())
+}
+
+/// Returns the built-in types, keyed by ABI type name
+fn built_in_types() -> &'static HashMap<&'static str, (PackFn, UnpackFn)> {
+    static BUILT_IN_TYPES: OnceLock<HashMap<&'static str, (PackFn, UnpackFn)>> = OnceLock::new();
+    BUILT_IN_TYPES.get_or_init(|| {
+        let types: [(&'static str, (PackFn, UnpackFn)); 31] = [
+            ("bool", (pack_serde::<bool>, unpack_serde::<bool>)),
+            ("int8", (pack_int::<i8>, unpack_value::<i8>)),
+            ("uint8", (pack_int::<u8>, unpack_value::<u8>)),
+            ("int16", (pack_int::<i16>, unpack_value::<i16>)),
+            ("uint16", (pack_int::<u16>, unpack_value::<u16>)),
+            ("int32", (pack_int::<i32>, unpack_value::<i32>)),
+            ("uint32", (pack_int::<u32>, unpack_value::<u32>)),
+            ("int64", (pack_int::<i64>, unpack_int64)),
+            ("uint64", (pack_int::<u64>, unpack_uint64)),
+            ("int128", (pack_int::<i128>, unpack_int128::<i128>)),
+            ("uint128", (pack_int::<u128>, unpack_int128::<u128>)),
+            ("varint32", (pack_int_as::<i32, VarInt32>, unpack_serde::<VarInt32>)),
+            ("varuint32", (pack_int_as::<u32, VarUint32>, unpack_serde::<VarUint32>)),
+            ("float32", (pack_float32, unpack_value::<f32>)),
+            ("float64", (pack_float64, unpack_value::<f64>)),
+            ("float128", (pack_serde::<Float128>, unpack_serde::<Float128>)),
+            ("time_point", (pack_serde::<TimePoint>, unpack_serde::<TimePoint>)),
+            ("time_point_sec", (pack_serde::<TimePointSec>, unpack_serde::<TimePointSec>)),
+            (
+                "block_timestamp_type",
+                (pack_serde::<BlockTimestamp>, unpack_serde::<BlockTimestamp>),
+            ),
+            ("name", (pack_serde::<Name>, unpack_serde::<Name>)),
+            ("bytes", (pack_serde::<Bytes>, unpack_serde::<Bytes>)),
+            ("string", (pack_serde::<String>, unpack_serde::<String>)),
+            ("checksum160", (pack_serde::<Checksum160>, unpack_serde::<Checksum160>)),
+            ("checksum256", (pack_serde::<Checksum256>, unpack_serde::<Checksum256>)),
+            ("checksum512", (pack_serde::<Checksum512>, unpack_serde::<Checksum512>)),
+            ("public_key", (pack_serde::<PublicKey>, unpack_serde::<PublicKey>)),
+            ("signature", (pack_serde::<Signature>, unpack_serde::<Signature>)),
+            ("symbol", (pack_serde::<Symbol>, unpack_serde::<Symbol>)),
+            ("symbol_code", (pack_serde::<SymbolCode>, unpack_serde::<SymbolCode>)),
+            ("asset", (pack_serde::<Asset>, unpack_serde::<Asset>)),
+            ("extended_asset", (pack_serde::<ExtendedAsset>, unpack_serde::<ExtendedAsset>)),
+        ];
+        types.into_iter().collect()
+    })
+}
+
+/// Returns the type with a trailing array `[]`, optional `?` or binary extension `$` marker removed
+fn fundamental_type(r#type: &str) -> &str {
+    r#type
+        .strip_suffix("[]")
+        .or_else(|| r#type.strip_suffix('?'))
+        .or_else(|| r#type.strip_suffix('$'))
+        .unwrap_or(r#type)
+}
+
+/// The `AbiSerializer` struct converts between JSON values and binary data using an ABI
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/abi_serializer.hpp>
+///
+/// Supports built-in types, type aliases, struct inheritance, arrays `[]`, optionals `?`,
+/// binary extensions `$` and variants.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::abi_serializer::AbiSerializer;
+/// use antelope::Name;
+/// use serde_json::json;
+///
+/// let abi = AbiSerializer::from_json(r#"{
+///     "version": "eosio::abi/1.1",
+///     "structs": [{ "name": "hi", "base": "", "fields": [{ "name": "user", "type": "name" }] }],
+///     "actions": [{ "name": "hi", "type": "hi", "ricardian_contract": "" }]
+/// }"#).unwrap();
+///
+/// let data = abi.encode_action(Name::from("hi"), &json!({ "user": "eosio" })).unwrap();
+/// assert_eq!(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0xea, 0x30, 0x55], data);
+/// assert_eq!(json!({ "user": "eosio" }), abi.decode_action(Name::from("hi"), &data).unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct AbiSerializer {
+    abi: AbiDef,
+    typedefs: HashMap<String, String>,
+    structs: HashMap<String, StructDef>,
+    variants: HashMap<String, VariantDef>,
+    actions: HashMap<Name, String>,
+    tables: HashMap<Name, String>,
+    action_results: HashMap<Name, String>,
+}
+
+impl AbiSerializer {
+    /// Creates a serializer for the given ABI, validating all of its type definitions
+    pub fn new(abi: AbiDef) -> Result<Self, AbiError> {
+        abi.check_version()?;
+
+        let mut typedefs = HashMap::new();
+        for t in &abi.types {
+            if built_in_types().contains_key(t.new_type_name.as_str()) {
+                return Err(AbiError::BadDefinition(format!(
+                    "type {} redefines a built-in type",
+                    t.new_type_name
+                )));
+            }
+            if typedefs.insert(t.new_type_name.clone(), t.r#type.clone()).is_some() {
+                return Err(AbiError::BadDefinition(format!("duplicate type definition {}", t.new_type_name)));
+            }
+        }
+        let mut structs = HashMap::new();
+        for s in &abi.structs {
+            if structs.insert(s.name.clone(), s.clone()).is_some() {
+                return Err(AbiError::BadDefinition(format!("duplicate struct definition {}", s.name)));
+            }
+        }
+        let mut variants = HashMap::new();
+        for v in &abi.variants {
+            if variants.insert(v.name.clone(), v.clone()).is_some() {
+                return Err(AbiError::BadDefinition(format!("duplicate variant definition {}", v.name)));
+            }
+        }
+        let mut actions = HashMap::new();
+        for a in &abi.actions {
+            if actions.insert(a.name, a.r#type.clone()).is_some() {
+                return Err(AbiError::BadDefinition(format!("duplicate action definition {}", a.name)));
+            }
+        }
+        let mut tables = HashMap::new();
+        for t in &abi.tables {
+            if tables.insert(t.name, t.r#type.clone()).is_some() {
+                return Err(AbiError::BadDefinition(format!("duplicate table definition {}", t.name)));
+            }
+        }
+        let action_results = abi.action_results.iter().map(|r| (r.name, r.result_type.clone())).collect();
+
+        let serializer = AbiSerializer {
+            abi,
+            typedefs,
+            structs,
+            variants,
+            actions,
+            tables,
+            action_results,
+        };
+        serializer.validate()?;
+        Ok(serializer)
+    }
+
+    /// Creates a serializer from the JSON representation of an ABI
+    pub fn from_json(json: &str) -> Result<Self, AbiError> {
+        AbiSerializer::new(AbiDef::from_json(json)?)
+    }
+
+    /// Creates a serializer from the binary representation of an ABI, as carried by `eosio::setabi`
+    pub fn from_binary(data: &[u8]) -> Result<Self, AbiError> {
+        AbiSerializer::new(AbiDef::from_binary(data)?)
+    }
+
+    /// Returns the ABI definition
+    pub fn abi(&self) -> &AbiDef {
+        &self.abi
+    }
+
+    fn validate(&self) -> Result<(), AbiError> {
+        for (name, target) in &self.typedefs {
+            self.check_type(target, &format!("type {}", name))?;
+        }
+        for s in self.structs.values() {
+            let mut base = s;
+            for _ in 0..=self.structs.len() {
+                if base.base.is_empty() {
+                    break;
+                }
+                base = self
+                    .structs
+                    .get(self.resolve_type(&base.base))
+                    .ok_or_else(|| AbiError::BadDefinition(format!("base {} of struct {} is not a struct", base.base, base.name)))?;
+                if base.name == s.name {
+                    return Err(AbiError::BadDefinition(format!("circular base of struct {}", s.name)));
+                }
+            }
+            for field in &s.fields {
+                self.check_type(&field.r#type, &format!("field {}.{}", s.name, field.name))?;
+            }
+        }
+        for v in self.variants.values() {
+            for t in &v.types {
+                self.check_type(t, &format!("variant {}", v.name))?;
+            }
+        }
+        for (name, t) in &self.actions {
+            self.check_type(t, &format!("action {}", name))?;
+        }
+        for (name, t) in &self.tables {
+            self.check_type(t, &format!("table {}", name))?;
+        }
+        for (name, t) in &self.action_results {
+            self.check_type(t, &format!("action result {}", name))?;
+        }
+        Ok(())
+    }
+
+    fn check_type(&self, r#type: &str, context: &str) -> Result<(), AbiError> {
+        if self.is_type(r#type) {
+            Ok(())
+        } else {
+            Err(AbiError::BadDefinition(format!("invalid type {} used in {}", r#type, context)))
+        }
+    }
+
+    /// Returns true if `type` is a built-in, alias, struct or variant type, possibly with a `[]`, `?` or `$` suffix
+    pub fn is_type(&self, r#type: &str) -> bool {
+        self.is_type_at_depth(r#type, 0)
+    }
+
+    fn is_type_at_depth(&self, r#type: &str, depth: usize) -> bool {
+        if depth > MAX_RECURSION_DEPTH {
+            return false;
+        }
+        let fundamental = fundamental_type(r#type);
+        if fundamental != r#type {
+            return self.is_type_at_depth(fundamental, depth + 1);
+        }
+        if built_in_types().contains_key(r#type) || self.structs.contains_key(r#type) || self.variants.contains_key(r#type) {
+            return true;
+        }
+        match self.typedefs.get(r#type) {
+            Some(target) => self.is_type_at_depth(target, depth + 1),
+            None => false,
+        }
+    }
+
+    /// Follows type aliases until a non-alias type is reached
+    pub fn resolve_type<'a>(&'a self, r#type: &'a str) -> &'a str {
+        let mut resolved = r#type;
+        for _ in 0..=self.typedefs.len() {
+            match self.typedefs.get(resolved) {
+                Some(target) => resolved = target,
+                None => break,
+            }
+        }
+        resolved
+    }
+
+    /// Returns the type of the data of the action named `name`
+    pub fn action_type(&self, name: Name) -> Option<&str> {
+        self.actions.get(&name).map(String::as_str)
+    }
+
+    /// Returns the type of the rows of the table named `name`
+    pub fn table_type(&self, name: Name) -> Option<&str> {
+        self.tables.get(&name).map(String::as_str)
+    }
+
+    /// Returns the type of the return value of the action named `name`
+    pub fn action_result_type(&self, name: Name) -> Option<&str> {
+        self.action_results.get(&name).map(String::as_str)
+    }
+
+    /// Encodes a JSON value of the given type into binary
+    pub fn variant_to_binary(&self, r#type: &str, value: &Value) -> Result<Vec<u8>, AbiError> {
+        let mut out = Vec::new();
+        self.pack_type(r#type, value, &mut out, 0)?;
+        Ok(out)
+    }
+
+    /// Decodes binary data of the given type into a JSON value, failing if any bytes are left over
+    pub fn binary_to_variant(&self, r#type: &str, data: &[u8]) -> Result<Value, AbiError> {
+        let mut data = data;
+        let value = self.unpack_type(r#type, &mut data, 0)?;
+        if !data.is_empty() {
+            return Err(AbiError::BadBinary(crate::UnpackError::TrailingBytes(data.len())));
+        }
+        Ok(value)
+    }
+
+    /// Encodes the JSON data of the action named `name`
+    pub fn encode_action(&self, name: Name, value: &Value) -> Result<Vec<u8>, AbiError> {
+        let r#type = self.action_type(name).ok_or_else(|| AbiError::UnknownAction(name.to_string()))?;
+        self.variant_to_binary(r#type, value)
+    }
+
+    /// Decodes the binary data of the action named `name`
+    pub fn decode_action(&self, name: Name, data: &[u8]) -> Result<Value, AbiError> {
+        let r#type = self.action_type(name).ok_or_else(|| AbiError::UnknownAction(name.to_string()))?;
+        self.binary_to_variant(r#type, data)
+    }
+
+    fn pack_type(&self, r#type: &str, value: &Value, out: &mut Vec<u8>, depth: usize) -> Result<(), AbiError> {
+        if depth > MAX_RECURSION_DEPTH {
+            return Err(AbiError::RecursionLimit);
+        }
+        let rtype = self.resolve_type(r#type);
+        if let Some((pack, _)) = built_in_types().get(rtype) {
+            return pack(value, out).map_err(|e| match e {
+                AbiError::BadValue(msg) => AbiError::BadValue(format!("{} ({})", msg, rtype)),
+                e => e,
+            });
+        }
+        if let Some(item_type) = rtype.strip_suffix("[]") {
+            let items = value
+                .as_array()
+                .ok_or_else(|| AbiError::BadValue(format!("expected array for {}, got {}", rtype, value)))?;
+            pack_varuint32(items.len() as u32, out);
+            for item in items {
+                self.pack_type(item_type, item, out, depth + 1)?;
+            }
+            return Ok(());
+        }
+        if let Some(inner_type) = rtype.strip_suffix('?') {
+            if value.is_null() {
+                false.pack(out);
+                return Ok(());
+            }
+            true.pack(out);
+            return self.pack_type(inner_type, value, out, depth + 1);
+        }
+        if let Some(inner_type) = rtype.strip_suffix('$') {
+            return self.pack_type(inner_type, value, out, depth + 1);
+        }
+        if let Some(variant) = self.variants.get(rtype) {
+            return self.pack_variant(variant, value, out, depth + 1);
+        }
+        if let Some(s) = self.structs.get(rtype) {
+            let object = value
+                .as_object()
+                .ok_or_else(|| AbiError::BadValue(format!("expected object for struct {}, got {}", s.name, value)))?;
+            return self.pack_struct(s, object, out, depth + 1);
+        }
+        Err(AbiError::UnknownType(rtype.to_string()))
+    }
+
+    fn pack_variant(&self, variant: &VariantDef, value: &Value, out: &mut Vec<u8>, depth: usize) -> Result<(), AbiError> {
+        let bad_value = || AbiError::BadValue(format!("expected [type, value] for variant {}, got {}", variant.name, value));
+        let (type_name, inner) = match value.as_array().map(Vec::as_slice) {
+            Some([Value::String(type_name), inner]) => (type_name, inner),
+            _ => return Err(bad_value()),
+        };
+        let index = variant
+            .types
+            .iter()
+            .position(|t| t == type_name)
+            .ok_or_else(|| AbiError::BadValue(format!("type {} is not part of variant {}", type_name, variant.name)))?;
+        pack_varuint32(index as u32, out);
+        self.pack_type(type_name, inner, out, depth + 1)
+    }
+
+    fn pack_struct(&self, s: &StructDef, object: &Map<String, Value>, out: &mut Vec<u8>, depth: usize) -> Result<(), AbiError> {
+        if depth > MAX_RECURSION_DEPTH {
+            return Err(AbiError::RecursionLimit);
+        }
+        if !s.base.is_empty() {
+            let base = &self.structs[self.resolve_type(&s.base)];
+            self.pack_struct(base, object, out, depth + 1)?;
+        }
+        let mut missing_extension = None;
+        for field in &s.fields {
+            let is_extension = field.r#type.ends_with('$');
+            match object.get(&field.name) {
+                Some(value) => {
+                    if let Some(missing) = missing_extension {
+                        return Err(AbiError::BadValue(format!(
+                            "field {}.{} is present but binary extension {} is missing",
+                            s.name, field.name, missing
+                        )));
+                    }
+                    self.pack_type(&field.r#type, value, out, depth + 1)?;
+                }
+                None if is_extension => missing_extension = missing_extension.or(Some(&field.name)),
+                None => return Err(AbiError::BadValue(format!("missing field {}.{}", s.name, field.name))),
+            }
+        }
+        Ok(())
+    }
+
+    fn unpack_type(&self, r#type: &str, data: &mut &[u8], depth: usize) -> Result<Value, AbiError> {
+        if depth > MAX_RECURSION_DEPTH {
+            return Err(AbiError::RecursionLimit);
+        }
+        let rtype = self.resolve_type(r#type);
+        if let Some((_, unpack)) = built_in_types().get(rtype) {
+            return unpack(data);
+        }
+        if let Some(item_type) = rtype.strip_suffix("[]") {
+            let len = unpack_varuint32(data)? as usize;
+            let mut items = Vec::with_capacity(len.min(data.len()));
+            for _ in 0..len {
+                items.push(self.unpack_type(item_type, data, depth + 1)?);
+            }
+            return Ok(Value::Array(items));
+        }
+        if let Some(inner_type) = rtype.strip_suffix('?') {
+            return match bool::unpack(data)? {
+                true => self.unpack_type(inner_type, data, depth + 1),
+                false => Ok(Value::Null),
+            };
+        }
+        if let Some(inner_type) = rtype.strip_suffix('$') {
+            return self.unpack_type(inner_type, data, depth + 1);
+        }
+        if let Some(variant) = self.variants.get(rtype) {
+            let index = unpack_varuint32(data)?;
+            let type_name = variant
+                .types
+                .get(index as usize)
+                .ok_or_else(|| AbiError::BadValue(format!("index {} is out of range for variant {}", index, variant.name)))?;
+            let inner = self.unpack_type(type_name, data, depth + 1)?;
+            return Ok(Value::Array(vec![Value::String(type_name.clone()), inner]));
+        }
+        if let Some(s) = self.structs.get(rtype) {
+            let mut object = Map::new();
+            self.unpack_struct(s, data, &mut object, depth + 1)?;
+            return Ok(Value::Object(object));
+        }
+        Err(AbiError::UnknownType(rtype.to_string()))
+    }
+
+    fn unpack_struct(&self, s: &StructDef, data: &mut &[u8], object: &mut Map<String, Value>, depth: usize) -> Result<(), AbiError> {
+        if depth > MAX_RECURSION_DEPTH {
+            return Err(AbiError::RecursionLimit);
+        }
+        if !s.base.is_empty() {
+            let base = &self.structs[self.resolve_type(&s.base)];
+            self.unpack_struct(base, data, object, depth + 1)?;
+        }
+        for field in &s.fields {
+            if data.is_empty() && field.r#type.ends_with('$') {
+                break;
+            }
+            let value = self.unpack_type(&field.r#type, data, depth + 1)?;
+            object.insert(field.name.clone(), value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::tests::{FULL_ABI, TOKEN_ABI};
+    use crate::bytes::{from_hex, to_hex};
+    use crate::UnpackError;
+    use serde_json::json;
+
+    fn roundtrip(abi: &AbiSerializer, r#type: &str, value: Value, expected: &str) {
+        let data = abi.variant_to_binary(r#type, &value).unwrap();
+        assert_eq!(to_hex(&data), expected, "{}", r#type);
+        assert_eq!(abi.binary_to_variant(r#type, &data).unwrap(), value, "{}", r#type);
+    }
+
+    #[test]
+    fn test_token_transfer() {
+        let abi = AbiSerializer::from_json(TOKEN_ABI).unwrap();
+        let transfer = json!({ "from": "eosio", "to": "eosio.token", "quantity": "1.0000 EOS", "memo": "hello" });
+        let hex_data = "0000000000ea305500a6823403ea3055102700000000000004454f53000000000568656c6c6f";
+        assert_eq!(to_hex(&abi.encode_action(Name::from("transfer"), &transfer).unwrap()), hex_data);
+        assert_eq!(
+            abi.decode_action(Name::from("transfer"), &from_hex(hex_data).unwrap()).unwrap(),
+            transfer
+        );
+        assert_eq!(
+            abi.decode_action(Name::from("transfer"), &from_hex(hex_data).unwrap())
+                .unwrap()
+                .to_string(),
+            r#"{"from":"eosio","to":"eosio.token","quantity":"1.0000 EOS","memo":"hello"}"#
+        );
+        assert_eq!(abi.table_type(Name::from("accounts")), Some("account"));
+        assert_eq!(abi.action_type(Name::from("issue")), Some("issue"));
+
+        let abi = AbiSerializer::from_binary(&crate::pack(abi.abi())).unwrap();
+        assert_eq!(to_hex(&abi.encode_action(Name::from("transfer"), &transfer).unwrap()), hex_data);
+    }
+
+    #[test]
+    fn test_built_in_types() {
+        let abi = AbiSerializer::new(AbiDef::default()).unwrap();
+        roundtrip(&abi, "bool", json!(true), "01");
+        roundtrip(&abi, "int8", json!(-1), "ff");
+        roundtrip(&abi, "uint16", json!(513), "0102");
+        roundtrip(&abi, "int32", json!(-2), "feffffff");
+        roundtrip(&abi, "uint32", json!(4294967295_u32), "ffffffff");
+        roundtrip(&abi, "int64", json!(-2147483648), "00000080ffffffff");
+        roundtrip(&abi, "int64", json!("-2147483649"), "ffffff7fffffffff");
+        roundtrip(&abi, "uint64", json!(4294967295_u32), "ffffffff00000000");
+        roundtrip(&abi, "uint64", json!("18446744073709551615"), "ffffffffffffffff");
+        roundtrip(&abi, "float32", json!(1.5), "0000c03f");
+        roundtrip(&abi, "float64", json!(-0.25), "000000000000d0bf");
+        roundtrip(&abi, "string", json!("abc"), "03616263");
+        roundtrip(&abi, "name", json!("eosio"), "0000000000ea3055");
+        roundtrip(&abi, "symbol", json!("4,EOS"), "04454f5300000000");
+        roundtrip(&abi, "symbol_code", json!("EOS"), "454f530000000000");
+        roundtrip(&abi, "asset", json!("1.0000 EOS"), "102700000000000004454f5300000000");
+        roundtrip(
+            &abi,
+            "extended_asset",
+            json!({ "quantity": "1.0000 EOS", "contract": "eosio.token" }),
+            "102700000000000004454f530000000000a6823403ea3055",
+        );
+        roundtrip(&abi, "time_point", json!("2018-06-15T19:17:47.500"), "e04d3912b36e0500");
+        roundtrip(&abi, "time_point_sec", json!("2018-06-15T19:17:47"), "db10245b");
+        roundtrip(&abi, "int128", json!("-1"), "ffffffffffffffffffffffffffffffff");
+        roundtrip(
+            &abi,
+            "uint128",
+            json!("340282366920938463463374607431768211455"),
+            "ffffffffffffffffffffffffffffffff",
+        );
+        roundtrip(&abi, "varint32", json!(-64), "7f");
+        roundtrip(&abi, "varuint32", json!(300), "ac02");
+        roundtrip(
+            &abi,
+            "float128",
+            json!("0x0000000000000000000000000000ff3f"),
+            "0000000000000000000000000000ff3f",
+        );
+        roundtrip(&abi, "block_timestamp_type", json!("2018-06-15T19:17:47.500"), "b79a6d45");
+        roundtrip(&abi, "bytes", json!("0a0b"), "020a0b");
+        roundtrip(
+            &abi,
+            "checksum160",
+            json!("0102030405060708090a0b0c0d0e0f1011121314"),
+            "0102030405060708090a0b0c0d0e0f1011121314",
+        );
+        roundtrip(&abi, "checksum256", json!("00".repeat(32)), &"00".repeat(32));
+        roundtrip(&abi, "checksum512", json!("ff".repeat(64)), &"ff".repeat(64));
+        roundtrip(
+            &abi,
+            "public_key",
+            json!("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV"),
+            "0002c0ded2bc1f1305fb0faac5e6c03ee3a1924234985427b6167ca569d13df435cf",
+        );
+        roundtrip(
+            &abi,
+            "signature",
+            json!("SIG_K1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx"),
+            "002056355ed1079822d2728886b449f0f4a2bbf48bf38698c0ebe8c7079768882b1c64ac07d7a4bd85cf96b8a74fdcafef1a4805f946177c609fdf31abe2463038e5",
+        );
+        roundtrip(&abi, "uint8[]", json!([1, 2]), "020102");
+        roundtrip(&abi, "string?", json!(null), "00");
+        roundtrip(&abi, "string?", json!("a"), "010161");
+
+        assert_eq!(abi.variant_to_binary("uint64", &json!("7")).unwrap(), [7, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            abi.variant_to_binary("uint128", &json!(7)).unwrap(),
+            [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert!(abi.variant_to_binary("varuint32", &json!(-1)).is_err());
+        assert!(abi
+            .variant_to_binary("public_key", &json!("PUB_K1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq64"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_full_abi() {
+        let abi = AbiSerializer::from_json(FULL_ABI).unwrap();
+        assert_eq!(abi.resolve_type("account_name"), "name");
+
+        // struct inheritance, arrays and optionals
+        roundtrip(
+            &abi,
+            "derived",
+            json!({ "owner": "alice", "values": [1, 2], "note": null }),
+            "0000000000855c3402010000000200000000",
+        );
+        roundtrip(
+            &abi,
+            "derived",
+            json!({ "owner": "alice", "values": [], "note": "hi" }),
+            "0000000000855c340001026869",
+        );
+
+        // variants
+        roundtrip(&abi, "value_type", json!(["uint64", 5]), "000500000000000000");
+        roundtrip(&abi, "value_type", json!(["string", "x"]), "010178");
+        roundtrip(
+            &abi,
+            "value_type",
+            json!(["derived", { "owner": "bob", "values": [3], "note": null }]),
+            "020000000000000e3d010300000000",
+        );
+
+        // binary extensions
+        roundtrip(&abi, "setvalue", json!({ "value": ["string", "x"] }), "010178");
+        roundtrip(&abi, "setvalue", json!({ "value": ["string", "x"], "extra": 9 }), "01017809");
+        assert_eq!(abi.action_result_type(Name::from("setvalue")), Some("uint64"));
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let abi = AbiSerializer::from_json(FULL_ABI).unwrap();
+        assert_eq!(
+            abi.variant_to_binary("derived", &json!({ "owner": "alice", "note": null })),
+            Err(AbiError::BadValue("missing field derived.values".to_string()))
+        );
+        assert!(matches!(abi.variant_to_binary("derived", &json!([])), Err(AbiError::BadValue(_))));
+        assert!(matches!(abi.variant_to_binary("uint8", &json!(256)), Err(AbiError::BadValue(_))));
+        assert!(matches!(abi.variant_to_binary("uint8", &json!(1.5)), Err(AbiError::BadValue(_))));
+        assert!(matches!(
+            abi.variant_to_binary("name", &json!("INVALID")),
+            Err(AbiError::BadValue(_))
+        ));
+        assert!(matches!(
+            abi.variant_to_binary("value_type", &json!(["bool", true])),
+            Err(AbiError::BadValue(_))
+        ));
+        assert!(matches!(
+            abi.variant_to_binary("value_type", &json!("x")),
+            Err(AbiError::BadValue(_))
+        ));
+        assert_eq!(
+            abi.variant_to_binary("unknown", &json!(1)),
+            Err(AbiError::UnknownType("unknown".to_string()))
+        );
+        assert_eq!(
+            abi.encode_action(Name::from("transfer"), &json!({})),
+            Err(AbiError::UnknownAction("transfer".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let abi = AbiSerializer::from_json(FULL_ABI).unwrap();
+        assert_eq!(
+            abi.binary_to_variant("uint32", &[1, 2]),
+            Err(AbiError::BadBinary(UnpackError::UnexpectedEof))
+        );
+        assert_eq!(
+            abi.binary_to_variant("uint8", &[1, 2]),
+            Err(AbiError::BadBinary(UnpackError::TrailingBytes(1)))
+        );
+        assert!(matches!(abi.binary_to_variant("value_type", &[3]), Err(AbiError::BadValue(_))));
+        assert_eq!(
+            abi.binary_to_variant("bool", &[2]),
+            Err(AbiError::BadBinary(UnpackError::BadBool(2)))
+        );
+    }
+
+    #[test]
+    fn test_recursion_limit() {
+        let abi = AbiSerializer::from_json(
+            r#"{
+                "version": "eosio::abi/1.1",
+                "structs": [{ "name": "node", "base": "", "fields": [{ "name": "next", "type": "node?" }] }]
+            }"#,
+        )
+        .unwrap();
+        roundtrip(&abi, "node", json!({ "next": { "next": null } }), "0100");
+        assert_eq!(abi.binary_to_variant("node", &[1; 64]), Err(AbiError::RecursionLimit));
+
+        let mut value = json!({ "next": null });
+        for _ in 0..64 {
+            value = json!({ "next": value });
+        }
+        assert_eq!(abi.variant_to_binary("node", &value), Err(AbiError::RecursionLimit));
+    }
+
+    #[test]
+    fn test_bad_definitions() {
+        let bad = |json: &str| matches!(AbiSerializer::from_json(json), Err(AbiError::BadDefinition(_)));
+        assert!(bad(
+            r#"{"version":"eosio::abi/1.1","types":[{"new_type_name":"a","type":"b"},{"new_type_name":"b","type":"a"}]}"#
+        ));
+        assert!(bad(
+            r#"{"version":"eosio::abi/1.1","types":[{"new_type_name":"name","type":"uint64"}]}"#
+        ));
+        assert!(bad(r#"{"version":"eosio::abi/1.1","types":[{"new_type_name":"a","type":"b"}]}"#));
+        assert!(bad(
+            r#"{"version":"eosio::abi/1.1","structs":[{"name":"a","base":"b","fields":[]},{"name":"b","base":"a","fields":[]}]}"#
+        ));
+        assert!(bad(
+            r#"{"version":"eosio::abi/1.1","structs":[{"name":"a","base":"uint8","fields":[]}]}"#
+        ));
+        assert!(bad(
+            r#"{"version":"eosio::abi/1.1","structs":[{"name":"a","base":"","fields":[{"name":"f","type":"c"}]}]}"#
+        ));
+        assert!(bad(r#"{"version":"eosio::abi/1.1","actions":[{"name":"a","type":"a"}]}"#));
+        assert!(bad(r#"{"version":"eosio::abi/1.1","variants":[{"name":"v","types":["x"]}]}"#));
+        assert!(bad(
+            r#"{"version":"eosio::abi/1.1","structs":[{"name":"a","fields":[]},{"name":"a","fields":[]}]}"#
+        ));
+    }
+}
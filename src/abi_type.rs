@@ -0,0 +1,494 @@
+use crate::abi::{AbiDef, ActionDef, ActionResultDef, ClausePair, ErrorMessage, FieldDef, StructDef, TableDef, TypeDef};
+use crate::abi_serializer::AbiSerializer;
+use crate::{
+    AbiError, Asset, BlockTimestamp, Bytes, Checksum160, Checksum256, Checksum512, ExtendedAsset, ExtendedSymbol, Float128, Name,
+    PublicKey, Signature, Symbol, SymbolCode, TimePoint, TimePointSec, VarInt32, VarUint32, VecView,
+};
+
+/// Derives `AbiType` for structs and variants, see the `antelope-derive` crate.
+#[cfg(feature = "derive")]
+pub use antelope_derive::AbiType;
+
+/// The `AbiType` trait describes how a Rust type appears in an ABI
+///
+/// Built-in types map to their ABI names, `Vec<T>` to `T[]` and `Option<T>` to `T?`.
+/// Structs and enums usually derive it, which also adds their `StructDef` or `VariantDef`.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::abi_type::AbiType;
+/// use antelope::{Asset, Name};
+///
+/// assert_eq!("name", Name::abi_type());
+/// assert_eq!("asset[]", Vec::<Asset>::abi_type());
+/// assert_eq!("string?", Option::<String>::abi_type());
+/// ```
+pub trait AbiType {
+    /// Returns the type name used in the ABI
+    fn abi_type() -> String;
+
+    /// Adds the definitions of this type and of the types it depends on to `abi`
+    fn add_definitions(_abi: &mut AbiDef) {}
+}
+
+macro_rules! impl_abi_type {
+    ($($t:ty => $name:literal),* $(,)?) => {
+        $(
+            impl AbiType for $t {
+                #[inline]
+                fn abi_type() -> String {
+                    String::from($name)
+                }
+            }
+        )*
+    };
+}
+
+impl_abi_type!(
+    bool => "bool",
+    i8 => "int8",
+    u8 => "uint8",
+    i16 => "int16",
+    u16 => "uint16",
+    i32 => "int32",
+    u32 => "uint32",
+    i64 => "int64",
+    u64 => "uint64",
+    i128 => "int128",
+    u128 => "uint128",
+    VarInt32 => "varint32",
+    VarUint32 => "varuint32",
+    f32 => "float32",
+    f64 => "float64",
+    Float128 => "float128",
+    TimePoint => "time_point",
+    TimePointSec => "time_point_sec",
+    BlockTimestamp => "block_timestamp_type",
+    Name => "name",
+    Bytes => "bytes",
+    String => "string",
+    Checksum160 => "checksum160",
+    Checksum256 => "checksum256",
+    Checksum512 => "checksum512",
+    PublicKey => "public_key",
+    Signature => "signature",
+    Symbol => "symbol",
+    SymbolCode => "symbol_code",
+    Asset => "asset",
+    ExtendedAsset => "extended_asset",
+    &str => "string",
+    &[u8] => "bytes",
+);
+
+impl AbiType for ExtendedSymbol {
+    /// `extended_symbol` is not a built-in type, contracts declare it as a struct
+    fn abi_type() -> String {
+        String::from("extended_symbol")
+    }
+
+    fn add_definitions(abi: &mut AbiDef) {
+        if abi.struct_def("extended_symbol").is_none() {
+            abi.structs.push(StructDef {
+                name: String::from("extended_symbol"),
+                base: String::new(),
+                fields: vec![
+                    FieldDef {
+                        name: String::from("sym"),
+                        r#type: String::from("symbol"),
+                    },
+                    FieldDef {
+                        name: String::from("contract"),
+                        r#type: String::from("name"),
+                    },
+                ],
+            });
+        }
+    }
+}
+
+impl<T: AbiType> AbiType for Vec<T> {
+    fn abi_type() -> String {
+        format!("{}[]", T::abi_type())
+    }
+
+    fn add_definitions(abi: &mut AbiDef) {
+        T::add_definitions(abi)
+    }
+}
+
+impl<T: AbiType> AbiType for VecView<'_, T> {
+    fn abi_type() -> String {
+        format!("{}[]", T::abi_type())
+    }
+
+    fn add_definitions(abi: &mut AbiDef) {
+        T::add_definitions(abi)
+    }
+}
+
+impl<T: AbiType> AbiType for Option<T> {
+    fn abi_type() -> String {
+        format!("{}?", T::abi_type())
+    }
+
+    fn add_definitions(abi: &mut AbiDef) {
+        T::add_definitions(abi)
+    }
+}
+
+impl<T: AbiType> AbiType for Box<T> {
+    fn abi_type() -> String {
+        T::abi_type()
+    }
+
+    fn add_definitions(abi: &mut AbiDef) {
+        T::add_definitions(abi)
+    }
+}
+
+/// Returns the ricardian contract template used for actions that don't provide one
+///
+/// Follows the `spec_version: "0.2.0"` front matter that CDT generates from `.contracts.md` files.
+pub fn ricardian_contract_placeholder(action: Name) -> String {
+    format!("---\nspec_version: \"0.2.0\"\ntitle: {action}\nsummary: '{action}'\nicon:\n---\n")
+}
+
+/// The `AbiBuilder` struct generates an ABI from Rust types implementing `AbiType`
+///
+/// Each action, table and action result adds its type and every type it depends on.
+/// `build` validates the result with `AbiSerializer`, the same checks `nodeos` runs on `setabi`.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::abi_type::{AbiBuilder, AbiType};
+/// use antelope::abi::{AbiDef, FieldDef, StructDef};
+/// use antelope::Name;
+///
+/// struct Hi;
+///
+/// impl AbiType for Hi {
+///     fn abi_type() -> String {
+///         "hi".to_string()
+///     }
+///
+///     fn add_definitions(abi: &mut AbiDef) {
+///         let fields = vec![FieldDef { name: "user".to_string(), r#type: "name".to_string() }];
+///         abi.structs.push(StructDef { name: "hi".to_string(), base: String::new(), fields });
+///     }
+/// }
+///
+/// let abi = AbiBuilder::new().action::<Hi>(Name::from("hi")).build().unwrap();
+/// assert_eq!("hi", abi.action(Name::from("hi")).unwrap().r#type);
+/// assert!(abi.to_json().starts_with(r#"{"version":"eosio::abi/1.3","#));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AbiBuilder {
+    abi: AbiDef,
+}
+
+impl AbiBuilder {
+    pub fn new() -> Self {
+        AbiBuilder::default()
+    }
+
+    /// Adds the definitions of `T` without referencing it from an action or table
+    pub fn add_type<T: AbiType>(mut self) -> Self {
+        T::add_definitions(&mut self.abi);
+        self
+    }
+
+    /// Adds a type alias
+    pub fn type_def(mut self, new_type_name: &str, r#type: &str) -> Self {
+        self.abi.types.push(TypeDef {
+            new_type_name: new_type_name.to_string(),
+            r#type: r#type.to_string(),
+        });
+        self
+    }
+
+    /// Adds an action taking `T` as its data, with a placeholder ricardian contract
+    pub fn action<T: AbiType>(self, name: Name) -> Self {
+        self.action_with_ricardian::<T>(name, &ricardian_contract_placeholder(name))
+    }
+
+    /// Adds an action taking `T` as its data, with the given ricardian contract
+    pub fn action_with_ricardian<T: AbiType>(mut self, name: Name, ricardian_contract: &str) -> Self {
+        T::add_definitions(&mut self.abi);
+        self.abi.actions.push(ActionDef {
+            name,
+            r#type: T::abi_type(),
+            ricardian_contract: ricardian_contract.to_string(),
+        });
+        self
+    }
+
+    /// Adds a `multi_index` table of `T` rows with an `i64` primary index
+    pub fn table<T: AbiType>(self, name: Name) -> Self {
+        self.table_with_index::<T>(name, "i64", &[])
+    }
+
+    /// Adds a table of `T` rows with the given index type and `(key_name, key_type)` pairs
+    pub fn table_with_index<T: AbiType>(mut self, name: Name, index_type: &str, keys: &[(&str, &str)]) -> Self {
+        T::add_definitions(&mut self.abi);
+        self.abi.tables.push(TableDef {
+            name,
+            index_type: index_type.to_string(),
+            key_names: keys.iter().map(|(name, _)| name.to_string()).collect(),
+            key_types: keys.iter().map(|(_, r#type)| r#type.to_string()).collect(),
+            r#type: T::abi_type(),
+        });
+        self
+    }
+
+    /// Adds the return value type of an action
+    pub fn action_result<T: AbiType>(mut self, name: Name) -> Self {
+        T::add_definitions(&mut self.abi);
+        self.abi.action_results.push(ActionResultDef {
+            name,
+            result_type: T::abi_type(),
+        });
+        self
+    }
+
+    /// Adds a ricardian clause, an empty `body` serves as a placeholder
+    pub fn ricardian_clause(mut self, id: &str, body: &str) -> Self {
+        self.abi.ricardian_clauses.push(ClausePair {
+            id: id.to_string(),
+            body: body.to_string(),
+        });
+        self
+    }
+
+    /// Adds the message of an error code
+    pub fn error_message(mut self, error_code: u64, error_msg: &str) -> Self {
+        self.abi.error_messages.push(ErrorMessage {
+            error_code,
+            error_msg: error_msg.to_string(),
+        });
+        self
+    }
+
+    /// Returns the ABI, failing if `nodeos` would reject it
+    pub fn build(self) -> Result<AbiDef, AbiError> {
+        AbiSerializer::new(self.abi.clone())?;
+        Ok(self.abi)
+    }
+
+    /// Returns the JSON of the ABI, failing if `nodeos` would reject it
+    pub fn to_json(self) -> Result<String, AbiError> {
+        Ok(self.build()?.to_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definitions<T: AbiType>() -> AbiDef {
+        let mut abi = AbiDef::default();
+        T::add_definitions(&mut abi);
+        abi
+    }
+
+    #[test]
+    fn test_built_in_types() {
+        assert_eq!(bool::abi_type(), "bool");
+        assert_eq!(Vec::<Option<u64>>::abi_type(), "uint64?[]");
+        assert_eq!(Box::<BlockTimestamp>::abi_type(), "block_timestamp_type");
+        assert_eq!(<&str>::abi_type(), "string");
+        assert_eq!(VecView::<&[u8]>::abi_type(), "bytes[]");
+        assert_eq!(definitions::<Vec<Asset>>(), AbiDef::default());
+        assert_eq!(definitions::<Option<ExtendedSymbol>>().structs.len(), 1);
+    }
+
+    #[test]
+    fn test_builder() {
+        let abi = AbiBuilder::new()
+            .type_def("account_name", "name")
+            .action_with_ricardian::<ExtendedSymbol>(Name::from("setsym"), "")
+            .table_with_index::<ExtendedSymbol>(Name::from("symbols"), "i64", &[("sym", "uint64")])
+            .action_result::<Asset>(Name::from("setsym"))
+            .ricardian_clause("UserAgreement", "")
+            .error_message(1, "bad symbol")
+            .build()
+            .unwrap();
+        assert_eq!(abi.structs.len(), 1);
+        assert_eq!(abi.table(Name::from("symbols")).unwrap().key_names, ["sym"]);
+        assert_eq!(abi.action_result(Name::from("setsym")).unwrap().result_type, "asset");
+        assert_eq!(abi.ricardian_clause("UserAgreement").unwrap().body, "");
+        assert_eq!(AbiDef::from_json(&abi.to_json()), Ok(abi));
+
+        assert_eq!(
+            ricardian_contract_placeholder(Name::from("transfer")),
+            "---\nspec_version: \"0.2.0\"\ntitle: transfer\nsummary: 'transfer'\nicon:\n---\n"
+        );
+        assert_eq!(
+            AbiBuilder::new().type_def("name", "uint64").build(),
+            Err(AbiError::BadDefinition("type name redefines a built-in type".to_string()))
+        );
+        assert!(AbiBuilder::new().type_def("a", "b").to_json().is_err());
+    }
+
+    #[cfg(feature = "derive")]
+    mod derive {
+        use serde_json::json;
+
+        use crate::abi::AbiDef;
+        use crate::abi_serializer::AbiSerializer;
+        use crate::abi_type::{AbiBuilder, AbiType as _};
+        use crate::{AbiType, Asset, Name, Pack, Unpack};
+
+        #[derive(Debug, PartialEq, Pack, Unpack, AbiType)]
+        struct Transfer {
+            from: Name,
+            to: Name,
+            quantity: Asset,
+            memo: String,
+        }
+
+        #[derive(Debug, PartialEq, Pack, Unpack, AbiType)]
+        #[antelope(name = "setconfig")]
+        struct SetConfig {
+            r#type: u8,
+            targets: Vec<Target>,
+            #[antelope(binary_extension)]
+            note: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, Pack, Unpack, AbiType)]
+        enum Target {
+            Account(Name),
+            Transfer(Box<Transfer>),
+        }
+
+        #[test]
+        fn test_derive() {
+            let mut abi = AbiDef::default();
+            SetConfig::add_definitions(&mut abi);
+            assert_eq!(SetConfig::abi_type(), "setconfig");
+            assert_eq!(
+                abi.structs.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+                ["setconfig", "transfer"]
+            );
+            assert_eq!(abi.struct_def("setconfig").unwrap().fields[0].name, "type");
+            assert_eq!(abi.struct_def("setconfig").unwrap().fields[1].r#type, "target[]");
+            assert_eq!(abi.struct_def("setconfig").unwrap().fields[2].r#type, "string$");
+            assert_eq!(abi.variant_def("target").unwrap().types, ["name", "transfer"]);
+
+            // the derived binary format matches the one described by the derived ABI
+            let serializer = AbiSerializer::new(abi).unwrap();
+            let value = SetConfig {
+                r#type: 1,
+                targets: vec![
+                    Target::Account(Name::from("alice")),
+                    Target::Transfer(Box::new(Transfer {
+                        from: Name::from("alice"),
+                        to: Name::from("bob"),
+                        quantity: Asset::from("1.0000 EOS"),
+                        memo: String::from("hi"),
+                    })),
+                ],
+                note: Some(String::from("note")),
+            };
+            let json = json!({
+                "type": 1,
+                "targets": [
+                    ["name", "alice"],
+                    ["transfer", { "from": "alice", "to": "bob", "quantity": "1.0000 EOS", "memo": "hi" }]
+                ],
+                "note": "note"
+            });
+            assert_eq!(serializer.variant_to_binary("setconfig", &json).unwrap(), value.packed());
+            assert_eq!(serializer.binary_to_variant("setconfig", &value.packed()).unwrap(), json);
+            assert_eq!(crate::unpack::<SetConfig>(&value.packed()), Ok(value));
+
+            let value = SetConfig {
+                r#type: 2,
+                targets: vec![],
+                note: None,
+            };
+            assert_eq!(value.packed(), [2, 0]);
+            assert_eq!(crate::unpack::<SetConfig>(&[2, 0]), Ok(value));
+            assert!(crate::unpack::<Target>(&[2]).is_err());
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct Account {
+            balance: Asset,
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct CurrencyStats {
+            supply: Asset,
+            max_supply: Asset,
+            issuer: Name,
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct Create {
+            issuer: Name,
+            maximum_supply: Asset,
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct Issue {
+            to: Name,
+            quantity: Asset,
+            memo: String,
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct Retire {
+            quantity: Asset,
+            memo: String,
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct Open {
+            owner: Name,
+            symbol: crate::Symbol,
+            ram_payer: Name,
+        }
+
+        #[allow(dead_code)]
+        #[derive(AbiType)]
+        struct Close {
+            owner: Name,
+            symbol: crate::Symbol,
+        }
+
+        #[test]
+        fn test_token_abi() {
+            let mut abi = AbiBuilder::new()
+                .action::<Close>(Name::from("close"))
+                .action::<Create>(Name::from("create"))
+                .action::<Issue>(Name::from("issue"))
+                .action::<Open>(Name::from("open"))
+                .action::<Retire>(Name::from("retire"))
+                .action::<Transfer>(Name::from("transfer"))
+                .table::<Account>(Name::from("accounts"))
+                .table::<CurrencyStats>(Name::from("stat"))
+                .build()
+                .unwrap();
+            assert_eq!(
+                abi.action(Name::from("transfer")).unwrap().ricardian_contract,
+                super::super::ricardian_contract_placeholder(Name::from("transfer"))
+            );
+
+            // matches the ABI generated by CDT for eosio.token, apart from ricardian contracts and ordering
+            let mut expected = AbiDef::from_json(crate::abi::tests::TOKEN_ABI).unwrap();
+            expected.version = abi.version.clone();
+            abi.actions.iter_mut().for_each(|a| a.ricardian_contract.clear());
+            abi.structs.sort_by(|a, b| a.name.cmp(&b.name));
+            assert_eq!(abi, expected);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::serializer::impl_pack_struct;
+use crate::{unpack, Bytes, Name, Pack, PermissionLevel, Unpack, UnpackError};
+
+/// The `Action` struct represents a call to a contract action
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/action.hpp>
+///
+/// `data` holds the packed action arguments, shown as hex in JSON like `nodeos` does without an ABI.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Action, Name, PermissionLevel};
+///
+/// let owner = Name::from("alice");
+/// let action = Action::new(Name::from("eosio"), Name::from("refund"), vec![PermissionLevel::from("alice@active")], &owner);
+/// assert_eq!(8, action.data.len());
+/// assert_eq!(owner, action.decode_data().unwrap());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Action {
+    /// The contract account
+    pub account: Name,
+    /// The action name
+    pub name: Name,
+    /// The permissions authorizing the action
+    pub authorization: Vec<PermissionLevel>,
+    /// The packed action arguments
+    pub data: Bytes,
+}
+
+impl Action {
+    /// Creates an action, packing its arguments
+    #[must_use]
+    pub fn new<T: Pack>(account: Name, name: Name, authorization: Vec<PermissionLevel>, data: &T) -> Self {
+        Action {
+            account,
+            name,
+            authorization,
+            data: Bytes::from(data.packed()),
+        }
+    }
+
+    /// Unpacks the action arguments, which must use all of `data`
+    pub fn decode_data<T: Unpack>(&self) -> Result<T, UnpackError> {
+        unpack(self.data.as_slice())
+    }
+}
+
+impl_pack_struct!(Action {
+    account,
+    name,
+    authorization,
+    data
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Asset;
+
+    #[derive(Debug, PartialEq)]
+    struct Transfer {
+        from: Name,
+        to: Name,
+        quantity: Asset,
+        memo: String,
+    }
+
+    impl_pack_struct!(Transfer { from, to, quantity, memo });
+
+    fn transfer() -> Action {
+        let data = Transfer {
+            from: Name::from("alice"),
+            to: Name::from("bob"),
+            quantity: Asset::from("1.0000 EOS"),
+            memo: "hi".to_string(),
+        };
+        Action::new(
+            Name::from("eosio.token"),
+            Name::from("transfer"),
+            vec![PermissionLevel::from("alice@active")],
+            &data,
+        )
+    }
+
+    #[test]
+    fn test_pack() {
+        let action = transfer();
+        let packed = action.packed();
+        assert_eq!(
+            crate::bytes::to_hex(&packed),
+            concat!(
+                "00a6823403ea3055",
+                "000000572d3ccdcd",
+                "01",
+                "0000000000855c34",
+                "00000000a8ed3232",
+                "23",
+                "0000000000855c34",
+                "0000000000000e3d",
+                "102700000000000004454f5300000000",
+                "026869"
+            )
+        );
+        assert_eq!(unpack::<Action>(&packed), Ok(action.clone()));
+        assert_eq!(action.decode_data::<Transfer>().unwrap().memo, "hi");
+        assert_eq!(action.decode_data::<(Name, Name)>(), Err(UnpackError::TrailingBytes(19)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let action = transfer();
+        let json = r#"{"account":"eosio.token","name":"transfer","authorization":[{"actor":"alice","permission":"active"}],"data":"0000000000855c340000000000000e3d102700000000000004454f5300000000026869"}"#;
+        assert_eq!(serde_json::to_string(&action).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Action>(json).unwrap(), action);
+    }
+}
@@ -0,0 +1,31 @@
+//! AES-256-CBC with PKCS#7 padding, keyed by a sha512 digest like `fc::aes_encrypt`.
+
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+
+use crate::Checksum512;
+
+type Encryptor = cbc::Encryptor<aes::Aes256>;
+type Decryptor = cbc::Decryptor<aes::Aes256>;
+
+/// Splits a sha512 digest into the AES key (first 32 bytes) and IV (next 16 bytes)
+fn key_iv(key: &Checksum512) -> (&[u8], &[u8]) {
+    (&key.data[..32], &key.data[32..48])
+}
+
+/// Encrypts `plain`
+pub(crate) fn encrypt(key: &Checksum512, plain: &[u8]) -> Vec<u8> {
+    let (key, iv) = key_iv(key);
+    Encryptor::new_from_slices(key, iv)
+        .expect("valid key and iv sizes")
+        .encrypt_padded_vec_mut::<Pkcs7>(plain)
+}
+
+/// Decrypts `cipher`, failing if the padding is invalid
+pub(crate) fn decrypt(key: &Checksum512, cipher: &[u8]) -> Option<Vec<u8>> {
+    let (key, iv) = key_iv(key);
+    Decryptor::new_from_slices(key, iv)
+        .expect("valid key and iv sizes")
+        .decrypt_padded_vec_mut::<Pkcs7>(cipher)
+        .ok()
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::{check, ParseError, Symbol, SymbolCode};
+use crate::{check, Pack, ParseError, Symbol, SymbolCode, Unpack, UnpackError};
 // use std::convert::From;
 /// The `Asset` struct represents a asset
 ///
@@ -138,7 +138,6 @@ impl FromStr for Asset {
 
 impl AsRef<Asset> for Asset {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &Asset {
         self
     }
@@ -244,7 +243,7 @@ impl std::ops::DivAssign<i64> for Asset {
      */
     fn div_assign(&mut self, a: i64) {
         check(a != 0, "divide by zero");
-        check(!(self.amount == std::i64::MIN && a == -1), "signed division overflow");
+        check(!(self.amount == i64::MIN && a == -1), "signed division overflow");
         self.amount /= a;
     }
 }
@@ -349,6 +348,24 @@ impl std::ops::Div<Asset> for Asset {
     }
 }
 
+impl Pack for Asset {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.amount.pack(out);
+        self.symbol.pack(out);
+    }
+}
+
+impl Unpack for Asset {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let amount = i64::unpack(data)?;
+        let symbol = Symbol::unpack(data)?;
+        Ok(Asset { amount, symbol })
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(Asset);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -694,7 +711,7 @@ mod tests {
     #[should_panic(expected = "signed division overflow")]
     fn test_asset_signed_division_overflow() {
         let mut asset1 = Asset {
-            amount: std::i64::MIN,
+            amount: i64::MIN,
             symbol: Symbol::from("4,SYM"),
         };
 
@@ -728,7 +745,7 @@ mod tests {
     #[should_panic(expected = "signed division overflow")]
     fn test_signed_division_overflow() {
         let asset = Asset {
-            amount: std::i64::MIN,
+            amount: i64::MIN,
             symbol: Symbol::from("4,SYM"),
         };
 
@@ -857,4 +874,24 @@ mod tests {
         let sym = Symbol::from("4,SYM");
         assert_eq!(Asset::from_amount(15000, sym).value(), 1.5);
     }
+
+    #[test]
+    fn test_pack() {
+        let asset = Asset::from("1.0000 EOS");
+        let bytes = [0x10, 0x27, 0, 0, 0, 0, 0, 0, 0x04, 0x45, 0x4f, 0x53, 0, 0, 0, 0];
+        assert_eq!(asset.packed(), bytes);
+        assert_eq!(crate::unpack::<Asset>(&bytes), Ok(asset));
+
+        let asset = Asset::from("-0.001 FOO");
+        assert_eq!(crate::unpack::<Asset>(&asset.packed()), Ok(asset));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = Asset::from("1.0000 EOS");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""1.0000 EOS""#);
+        assert_eq!(serde_json::from_str::<Asset>(r#""1.0000 EOS""#).unwrap(), value);
+        assert!(serde_json::from_str::<Asset>(r#""1.0000""#).is_err());
+    }
 }
@@ -0,0 +1,288 @@
+use crate::serializer::impl_pack_struct;
+use crate::{AuthorityError, PermissionLevel, PublicKey};
+
+/// The maximum `wait_sec` of a [`WaitWeight`], the default maximum transaction delay of `nodeos`
+pub const MAX_WAIT_SEC: u32 = 45 * 24 * 60 * 60;
+
+/// The maximum number of keys, accounts and waits in an [`Authority`], combined
+pub const MAX_AUTHORITY_ENTRIES: usize = 1 << 16;
+
+/// The `KeyWeight` struct represents a key satisfying part of an [`Authority`]
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/authority.hpp>
+#[derive(Eq, Clone, Debug, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyWeight {
+    /// The key
+    pub key: PublicKey,
+    /// The weight of a signature by the key
+    pub weight: u16,
+}
+
+impl_pack_struct!(KeyWeight { key, weight });
+
+/// The `PermissionLevelWeight` struct represents an account permission satisfying part of an [`Authority`]
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/authority.hpp>
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PermissionLevelWeight {
+    /// The account permission
+    pub permission: PermissionLevel,
+    /// The weight of the permission when it is satisfied
+    pub weight: u16,
+}
+
+impl_pack_struct!(PermissionLevelWeight { permission, weight });
+
+/// The `WaitWeight` struct represents a delay satisfying part of an [`Authority`]
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/authority.hpp>
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaitWeight {
+    /// The delay of the transaction in seconds
+    pub wait_sec: u32,
+    /// The weight of the delay when the transaction waited at least `wait_sec`
+    pub weight: u16,
+}
+
+impl_pack_struct!(WaitWeight { wait_sec, weight });
+
+/// The `Authority` struct represents the keys, permissions and delays able to satisfy an account permission
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/authority.hpp>
+///
+/// The permission is satisfied when the weights of the provided keys, satisfied permissions and delay
+/// add up to `threshold`. `updateauth` and `newaccount` only accept authorities passing [`Authority::validate`].
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Authority, AuthorityError, PermissionLevel, PublicKey};
+///
+/// let mut auth = Authority::from_key(PublicKey::from("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV"));
+/// assert_eq!(Ok(()), auth.validate());
+///
+/// auth.threshold = 2;
+/// assert_eq!(Err(AuthorityError::ThresholdUnreachable(2)), auth.validate());
+/// auth.add_permission(PermissionLevel::from("alice@active"), 1);
+/// assert_eq!(Ok(()), auth.validate());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Authority {
+    /// The weight required to satisfy the authority
+    pub threshold: u32,
+    /// The keys, sorted and unique
+    pub keys: Vec<KeyWeight>,
+    /// The account permissions, sorted and unique
+    pub accounts: Vec<PermissionLevelWeight>,
+    /// The delays, sorted and unique
+    pub waits: Vec<WaitWeight>,
+}
+
+impl_pack_struct!(Authority {
+    threshold,
+    keys,
+    accounts,
+    waits
+});
+
+impl Authority {
+    /// Creates an authority satisfied by a signature of `key`
+    #[must_use]
+    pub fn from_key(key: PublicKey) -> Self {
+        Authority {
+            threshold: 1,
+            keys: vec![KeyWeight { key, weight: 1 }],
+            ..Default::default()
+        }
+    }
+
+    /// Creates an authority satisfied by the account permission `permission`
+    #[must_use]
+    pub fn from_permission(permission: PermissionLevel) -> Self {
+        Authority {
+            threshold: 1,
+            accounts: vec![PermissionLevelWeight { permission, weight: 1 }],
+            ..Default::default()
+        }
+    }
+
+    /// Adds a key, keeping the keys sorted
+    pub fn add_key(&mut self, key: PublicKey, weight: u16) {
+        self.keys.push(KeyWeight { key, weight });
+        self.keys.sort_by(|a, b| a.key.cmp(&b.key));
+    }
+
+    /// Adds an account permission, keeping the accounts sorted
+    pub fn add_permission(&mut self, permission: PermissionLevel, weight: u16) {
+        self.accounts.push(PermissionLevelWeight { permission, weight });
+        self.accounts.sort_by_key(|a| a.permission);
+    }
+
+    /// Adds a delay, keeping the waits sorted
+    pub fn add_wait(&mut self, wait_sec: u32, weight: u16) {
+        self.waits.push(WaitWeight { wait_sec, weight });
+        self.waits.sort_by_key(|w| w.wait_sec);
+    }
+
+    /// Returns the sum of all weights, the highest weight the authority can be satisfied with
+    #[must_use]
+    pub fn total_weight(&self) -> u64 {
+        let keys = self.keys.iter().map(|k| k.weight as u64);
+        let accounts = self.accounts.iter().map(|a| a.weight as u64);
+        let waits = self.waits.iter().map(|w| w.weight as u64);
+        keys.chain(accounts).chain(waits).sum()
+    }
+
+    /**
+     * Checks the authority like `nodeos` does when validating `updateauth` and `newaccount`
+     *
+     * There can be at most [`MAX_AUTHORITY_ENTRIES`] entries, keys, accounts and waits must each be
+     * sorted and unique, every weight must be non-zero, waits must be between 1 second and
+     * [`MAX_WAIT_SEC`], and the threshold must be non-zero and reachable.
+     *
+     * The zero weight check is stricter than `nodeos`, which accepts zero weights as long as the
+     * threshold is reachable, but such entries can never contribute to satisfying the authority.
+     */
+    pub fn validate(&self) -> Result<(), AuthorityError> {
+        let entries = self.keys.len() + self.accounts.len() + self.waits.len();
+        if entries > MAX_AUTHORITY_ENTRIES {
+            return Err(AuthorityError::TooManyEntries(entries));
+        }
+        if !self.keys.windows(2).all(|w| w[0].key < w[1].key) {
+            return Err(AuthorityError::UnsortedKeys);
+        }
+        if !self.accounts.windows(2).all(|w| w[0].permission < w[1].permission) {
+            return Err(AuthorityError::UnsortedAccounts);
+        }
+        if !self.waits.windows(2).all(|w| w[0].wait_sec < w[1].wait_sec) {
+            return Err(AuthorityError::UnsortedWaits);
+        }
+        let weights = self.keys.iter().map(|k| k.weight);
+        let weights = weights.chain(self.accounts.iter().map(|a| a.weight));
+        if weights.chain(self.waits.iter().map(|w| w.weight)).any(|weight| weight == 0) {
+            return Err(AuthorityError::ZeroWeight);
+        }
+        if self.waits.first().is_some_and(|w| w.wait_sec == 0) {
+            return Err(AuthorityError::ZeroWait);
+        }
+        if let Some(wait) = self.waits.iter().find(|w| w.wait_sec > MAX_WAIT_SEC) {
+            return Err(AuthorityError::WaitTooLong(wait.wait_sec));
+        }
+        if self.threshold == 0 {
+            return Err(AuthorityError::ZeroThreshold);
+        }
+        if self.threshold as u64 > self.total_weight() {
+            return Err(AuthorityError::ThresholdUnreachable(self.threshold));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pack, unpack};
+
+    const KEY_A: &str = "EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV";
+    const KEY_B: &str = "PUB_R1_6FPFZqw5ahYrR9jD96yDbbDNTdKtNqRbze6oTDLntrsANgQKZu";
+
+    fn multisig() -> Authority {
+        let mut auth = Authority {
+            threshold: 3,
+            ..Default::default()
+        };
+        auth.add_key(PublicKey::from(KEY_B), 1);
+        auth.add_key(PublicKey::from(KEY_A), 1);
+        auth.add_permission(PermissionLevel::from("bob@active"), 1);
+        auth.add_permission(PermissionLevel::from("alice@owner"), 1);
+        auth.add_wait(3600, 1);
+        auth
+    }
+
+    #[test]
+    fn test_validate() {
+        let auth = multisig();
+        assert_eq!(auth.validate(), Ok(()));
+        assert_eq!(auth.total_weight(), 5);
+        assert_eq!(auth.keys[0].key, PublicKey::from(KEY_A));
+        assert_eq!(auth.accounts[0].permission, PermissionLevel::from("alice@owner"));
+        assert_eq!(Authority::from_permission(PermissionLevel::from("alice@active")).validate(), Ok(()));
+
+        // the same permission with different weights is still a duplicate
+        let mut invalid = auth.clone();
+        invalid.add_permission(PermissionLevel::from("alice@owner"), 2);
+        assert_eq!(invalid.validate(), Err(AuthorityError::UnsortedAccounts));
+        let mut invalid = auth.clone();
+        invalid.keys.reverse();
+        assert_eq!(invalid.validate(), Err(AuthorityError::UnsortedKeys));
+        let mut invalid = auth.clone();
+        invalid.add_wait(3600, 1);
+        assert_eq!(invalid.validate(), Err(AuthorityError::UnsortedWaits));
+
+        let mut invalid = auth.clone();
+        invalid.keys[1].weight = 0;
+        assert_eq!(invalid.validate(), Err(AuthorityError::ZeroWeight));
+        let mut invalid = auth.clone();
+        invalid.add_wait(MAX_WAIT_SEC, 1);
+        assert_eq!(invalid.validate(), Ok(()));
+        invalid.waits[1].wait_sec += 1;
+        assert_eq!(invalid.validate(), Err(AuthorityError::WaitTooLong(MAX_WAIT_SEC + 1)));
+        let mut invalid = auth.clone();
+        invalid.add_wait(0, 1);
+        assert_eq!(invalid.validate(), Err(AuthorityError::ZeroWait));
+
+        let mut invalid = auth.clone();
+        invalid.threshold = 0;
+        assert_eq!(invalid.validate(), Err(AuthorityError::ZeroThreshold));
+        invalid.threshold = 6;
+        assert_eq!(invalid.validate(), Err(AuthorityError::ThresholdUnreachable(6)));
+        assert_eq!(Authority::default().validate(), Err(AuthorityError::ZeroThreshold));
+
+        let mut large = Authority {
+            threshold: 1,
+            ..Default::default()
+        };
+        large.waits = (1..=MAX_AUTHORITY_ENTRIES as u32)
+            .map(|wait_sec| WaitWeight { wait_sec, weight: 1 })
+            .collect();
+        assert_eq!(large.validate(), Ok(()));
+        large.add_key(PublicKey::from(KEY_A), 1);
+        assert_eq!(large.validate(), Err(AuthorityError::TooManyEntries(MAX_AUTHORITY_ENTRIES + 1)));
+    }
+
+    #[test]
+    fn test_pack() {
+        let auth = Authority::from_key(PublicKey::from(KEY_A));
+        let packed = pack(&auth);
+        assert_eq!(&packed[..5], [1, 0, 0, 0, 1]);
+        assert_eq!(packed.len(), 4 + 1 + (1 + 33 + 2) + 1 + 1);
+        assert_eq!(unpack::<Authority>(&packed), Ok(auth));
+
+        let auth = multisig();
+        assert_eq!(unpack::<Authority>(&pack(&auth)), Ok(auth));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let auth = multisig();
+        let json = serde_json::to_value(&auth).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "threshold": 3,
+                "keys": [{"key": KEY_A, "weight": 1}, {"key": KEY_B, "weight": 1}],
+                "accounts": [
+                    {"permission": {"actor": "alice", "permission": "owner"}, "weight": 1},
+                    {"permission": {"actor": "bob", "permission": "active"}, "weight": 1}
+                ],
+                "waits": [{"wait_sec": 3600, "weight": 1}]
+            })
+        );
+        assert_eq!(serde_json::from_value::<Authority>(json).unwrap(), auth);
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{milliseconds, Pack, ParseError, TimePoint, TimePointSec, Unpack, UnpackError};
+
+/// The `BlockTimestamp` struct represents a block time, counted in half-second slots
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp>
+///
+/// Slots are counted from 2000-01-01T00:00:00.000 and written like a `TimePoint`.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::BlockTimestamp;
+///
+/// let timestamp = BlockTimestamp::from("2018-06-15T19:17:47.500");
+/// assert_eq!(1164810935, timestamp.slot);
+/// assert_eq!("2018-06-15T19:17:47.500", timestamp.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+pub struct BlockTimestamp {
+    /// The number of half-second slots since the epoch
+    pub slot: u32,
+}
+
+impl BlockTimestamp {
+    /// Milliseconds between blocks
+    pub const BLOCK_INTERVAL_MS: i64 = 500;
+
+    /// Epoch is 2000-01-01T00:00:00.000, in milliseconds since the Unix epoch
+    pub const BLOCK_TIMESTAMP_EPOCH: i64 = 946684800000;
+
+    pub fn new() -> BlockTimestamp {
+        BlockTimestamp { slot: 0 }
+    }
+
+    pub fn maximum() -> BlockTimestamp {
+        BlockTimestamp { slot: 0xffff }
+    }
+
+    pub fn min() -> BlockTimestamp {
+        BlockTimestamp { slot: 0 }
+    }
+
+    /**
+     * Returns the timestamp of the following block
+     */
+    pub fn next(&self) -> BlockTimestamp {
+        BlockTimestamp::from(self.slot.checked_add(1).expect("block timestamp overflow"))
+    }
+
+    pub fn to_time_point(&self) -> TimePoint {
+        TimePoint::from(milliseconds(
+            self.slot as i64 * Self::BLOCK_INTERVAL_MS + Self::BLOCK_TIMESTAMP_EPOCH,
+        ))
+    }
+
+    /**
+     * Converts a TimePoint to the slot containing it, rounding down to the block interval
+     */
+    pub fn from_time_point(tp: TimePoint) -> Self {
+        let millis = tp.time_since_epoch().count() / 1000;
+        let slot = (millis - Self::BLOCK_TIMESTAMP_EPOCH) / Self::BLOCK_INTERVAL_MS;
+        BlockTimestamp::from(slot as u32)
+    }
+}
+
+impl FromStr for BlockTimestamp {
+    type Err = ParseError;
+
+    /**
+     * Parse BlockTimestamp from string formatted as "2018-06-15T19:17:47.500"
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tp = TimePoint::from_str(s)?;
+        let millis = tp.time_since_epoch().count() / 1000;
+        let slot = (millis - Self::BLOCK_TIMESTAMP_EPOCH) / Self::BLOCK_INTERVAL_MS;
+        let slot = u32::try_from(slot).map_err(|_| ParseError::BadTimePoint(s.to_string()))?;
+        Ok(BlockTimestamp { slot })
+    }
+}
+
+impl From<&str> for BlockTimestamp {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse block timestamp: {}", e))
+    }
+}
+
+impl From<u32> for BlockTimestamp {
+    #[inline]
+    fn from(slot: u32) -> Self {
+        BlockTimestamp { slot }
+    }
+}
+
+impl From<TimePoint> for BlockTimestamp {
+    fn from(tp: TimePoint) -> Self {
+        BlockTimestamp::from_time_point(tp)
+    }
+}
+
+impl From<TimePointSec> for BlockTimestamp {
+    fn from(tps: TimePointSec) -> Self {
+        BlockTimestamp::from_time_point(TimePoint::from(tps))
+    }
+}
+
+impl From<BlockTimestamp> for TimePoint {
+    fn from(timestamp: BlockTimestamp) -> Self {
+        timestamp.to_time_point()
+    }
+}
+
+impl fmt::Display for BlockTimestamp {
+    /**
+     * Converts the BlockTimestamp into string
+     *
+     * @return String in the form of "%Y-%m-%dT%H:%M:%S.%3f" format (e.g. "2018-06-15T19:17:47.500")
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tp = self.to_time_point();
+        let millis = tp.time_since_epoch().count() / 1000 % 1000;
+        write!(f, "{}.{:03}", TimePointSec::from(tp), millis)
+    }
+}
+
+impl Pack for BlockTimestamp {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.slot.pack(out)
+    }
+}
+
+impl Unpack for BlockTimestamp {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(BlockTimestamp::from(u32::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(BlockTimestamp);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_point() {
+        assert_eq!(BlockTimestamp::new().to_string(), "2000-01-01T00:00:00.000");
+        assert_eq!(BlockTimestamp::from(1).to_string(), "2000-01-01T00:00:00.500");
+        assert_eq!(BlockTimestamp::from(1).next(), BlockTimestamp::from(2));
+        assert_eq!(
+            BlockTimestamp::from(TimePoint::from_str("2018-06-15T19:17:47.999").unwrap()).to_string(),
+            "2018-06-15T19:17:47.500"
+        );
+        assert_eq!(
+            TimePoint::from(BlockTimestamp::from(3)),
+            TimePoint::from(milliseconds(BlockTimestamp::BLOCK_TIMESTAMP_EPOCH + 1500))
+        );
+        assert_eq!(BlockTimestamp::from(TimePointSec::from(946684801)), BlockTimestamp::from(2));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(BlockTimestamp::from_str("2000-01-01T00:00:01"), Ok(BlockTimestamp::from(2)));
+        assert_eq!(BlockTimestamp::from_str("2000-01-01T00:00:01.250"), Ok(BlockTimestamp::from(2)));
+        assert_eq!(
+            BlockTimestamp::from_str("1999-12-31T23:59:59"),
+            Err(ParseError::BadTimePoint("1999-12-31T23:59:59".to_string()))
+        );
+        assert!(BlockTimestamp::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn test_pack() {
+        let timestamp = BlockTimestamp::from("2018-06-15T19:17:47.500");
+        assert_eq!(timestamp.packed(), [0xb7, 0x9a, 0x6d, 0x45]);
+        assert_eq!(crate::unpack::<BlockTimestamp>(&timestamp.packed()), Ok(timestamp));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let timestamp = BlockTimestamp::from(1164810935);
+        assert_eq!(serde_json::to_string(&timestamp).unwrap(), r#""2018-06-15T19:17:47.500""#);
+        assert_eq!(
+            serde_json::from_str::<BlockTimestamp>(r#""2018-06-15T19:17:47.500""#).unwrap(),
+            timestamp
+        );
+    }
+}
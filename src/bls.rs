@@ -0,0 +1,581 @@
+use std::fmt;
+use std::str::FromStr;
+
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+
+use crate::{read_bytes, ripemd160, Pack, ParseError, Unpack, UnpackError};
+
+/// The size of a BLS public key, an affine G1 point
+pub const BLS_PUBLIC_KEY_SIZE: usize = 96;
+
+/// The size of a BLS signature, an affine G2 point
+pub const BLS_SIGNATURE_SIZE: usize = 192;
+
+/// The size of a BLS private key, a scalar
+pub const BLS_PRIVATE_KEY_SIZE: usize = 32;
+
+/// base64url, written without padding and read with or without it
+const BASE64URL: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Encodes `data` followed by the first 4 bytes of its ripemd160, like `fc::crypto::blslib`
+fn encode_bls(prefix: &str, data: &[u8]) -> String {
+    let checksum = ripemd160(data);
+    format!("{}{}", prefix, BASE64URL.encode([data, &checksum.data[..4]].concat()))
+}
+
+/// Decodes a string written by [`encode_bls`], verifying its prefix, size and checksum
+fn decode_bls<const N: usize>(s: &str, prefix: &str) -> Option<[u8; N]> {
+    let mut bytes = BASE64URL.decode(s.strip_prefix(prefix)?).ok()?;
+    if bytes.len() != N + 4 {
+        return None;
+    }
+    let checksum = bytes.split_off(N);
+    (checksum == ripemd160(&bytes).data[..4]).then(|| bytes.try_into().expect("checked length"))
+}
+
+macro_rules! impl_bls_point {
+    ($t:ident, $size:ident, $prefix:literal, $err:ident) => {
+        impl $t {
+            /// Creates the value from its affine little-endian bytes, as `nodeos` packs it
+            pub fn from_bytes(data: &[u8]) -> Result<Self, UnpackError> {
+                let data = data
+                    .try_into()
+                    .map_err(|_| UnpackError::BadData(format!("invalid {} size: {}", stringify!($t), data.len())))?;
+                Ok($t { data })
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = ParseError;
+
+            #[doc = concat!(" Parse ", stringify!($t), " from \"", $prefix, "...\" strings, verifying the checksum")]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                decode_bls(s, $prefix)
+                    .map(|data| $t { data })
+                    .ok_or_else(|| ParseError::$err(s.to_string()))
+            }
+        }
+
+        impl From<&str> for $t {
+            fn from(str: &str) -> Self {
+                Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse {}: {}", stringify!($t), e))
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&encode_bls($prefix, &self.data))
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($t)).field(&self.to_string()).finish()
+            }
+        }
+
+        impl Pack for $t {
+            fn pack(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.data);
+            }
+        }
+
+        impl Unpack for $t {
+            fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+                $t::from_bytes(read_bytes(data, $size)?)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        crate::serde_helpers::impl_serde_string!($t);
+    };
+}
+
+/// The `BlsPublicKey` struct represents the BLS12-381 public key of a Savanna finalizer
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/bls_public_key.hpp>
+///
+/// `data` holds the affine G1 point, each coordinate little-endian, as packed in finalizer policies.
+/// The string form is `PUB_BLS_` followed by the base64url of the data and a ripemd160 checksum.
+/// Parsing only checks the encoding, the point is checked by the `crypto` operations.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::BlsPublicKey;
+///
+/// let key = BlsPublicKey::from("PUB_BLS_mven5fYYBnwGTWV3Gwzh1g_iprn_jTZPZOLlc6jE6iIE6g1P5_CJeZG72eyw0ZEExDaf1OV4Qth6nqlOlBdOke6cfAN43QQdwMXkUEvKjcM7GqqtYur8NIA-XVdhcM0X6EV6Og");
+/// assert_eq!(96, key.data.len());
+/// ```
+#[derive(Eq, Copy, Clone, PartialEq, PartialOrd, Ord, Hash)]
+pub struct BlsPublicKey {
+    /// The affine little-endian G1 point
+    pub data: [u8; BLS_PUBLIC_KEY_SIZE],
+}
+
+impl_bls_point!(BlsPublicKey, BLS_PUBLIC_KEY_SIZE, "PUB_BLS_", BadPublicKey);
+
+/// The `BlsSignature` struct represents a BLS12-381 signature, such as a finality vote or a quorum certificate
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/bls_signature.hpp>
+///
+/// `data` holds the affine G2 point, each coordinate little-endian with the `c0` component first.
+/// The string form is `SIG_BLS_` followed by the base64url of the data and a ripemd160 checksum.
+#[derive(Eq, Copy, Clone, PartialEq, PartialOrd, Ord, Hash)]
+pub struct BlsSignature {
+    /// The affine little-endian G2 point
+    pub data: [u8; BLS_SIGNATURE_SIZE],
+}
+
+impl_bls_point!(BlsSignature, BLS_SIGNATURE_SIZE, "SIG_BLS_", BadSignature);
+
+#[cfg(feature = "crypto")]
+pub use self::private_key::*;
+
+#[cfg(feature = "crypto")]
+mod curve {
+    //! Savanna BLS over BLS12-381 with the proof of possession ciphersuite, keys on G1 and signatures on G2.
+
+    use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+    use bls12_381::{multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt};
+
+    /// The domain separation tag of signatures
+    pub(super) const SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// The domain separation tag of proofs of possession
+    pub(super) const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// Converts between big-endian `c1 || c0` and little-endian `c0 || c1` coordinates
+    fn swap_endianness<const N: usize>(bytes: &[u8; N], limbs: usize) -> [u8; N] {
+        let mut out = [0; N];
+        let size = N / limbs;
+        for (i, limb) in bytes.chunks(size).enumerate() {
+            // in G2, each coordinate is two limbs whose order is also reversed
+            let j = if limbs == 4 { i ^ 1 } else { i };
+            for (k, b) in limb.iter().rev().enumerate() {
+                out[j * size + k] = *b;
+            }
+        }
+        out
+    }
+
+    /// Decodes a little-endian G1 point, checking it is on the curve and in the subgroup
+    pub(super) fn g1_from_le(data: &[u8; 96]) -> Option<G1Affine> {
+        if data.iter().all(|b| *b == 0) {
+            return Some(G1Affine::identity());
+        }
+        G1Affine::from_uncompressed(&swap_endianness(data, 2)).into()
+    }
+
+    pub(super) fn g1_to_le(point: &G1Affine) -> [u8; 96] {
+        if bool::from(point.is_identity()) {
+            return [0; 96];
+        }
+        swap_endianness(&point.to_uncompressed(), 2)
+    }
+
+    /// Decodes a little-endian G2 point, checking it is on the curve and in the subgroup
+    pub(super) fn g2_from_le(data: &[u8; 192]) -> Option<G2Affine> {
+        if data.iter().all(|b| *b == 0) {
+            return Some(G2Affine::identity());
+        }
+        G2Affine::from_uncompressed(&swap_endianness(data, 4)).into()
+    }
+
+    pub(super) fn g2_to_le(point: &G2Affine) -> [u8; 192] {
+        if bool::from(point.is_identity()) {
+            return [0; 192];
+        }
+        swap_endianness(&point.to_uncompressed(), 4)
+    }
+
+    /// Hashes `msg` to G2 as specified by RFC 9380
+    pub(super) fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2Affine {
+        <G2Projective as HashToCurve<ExpandMsgXmd<sha2_09::Sha256>>>::hash_to_curve(msg, dst).into()
+    }
+
+    /// Checks that `sig` is the aggregate signature of each `(key, message hash)` pair
+    pub(super) fn verify(pairs: &[(G1Affine, G2Affine)], sig: &G2Affine) -> bool {
+        if pairs.is_empty() || pairs.iter().any(|(key, _)| bool::from(key.is_identity())) {
+            return false;
+        }
+        let prepared: Vec<(G1Affine, G2Prepared)> = pairs
+            .iter()
+            .map(|(key, hash)| (*key, G2Prepared::from(*hash)))
+            .chain([(-G1Affine::generator(), G2Prepared::from(*sig))])
+            .collect();
+        let terms: Vec<(&G1Affine, &G2Prepared)> = prepared.iter().map(|(key, hash)| (key, hash)).collect();
+        multi_miller_loop(&terms).final_exponentiation() == Gt::identity()
+    }
+
+    pub(super) fn sum_g1(points: impl Iterator<Item = G1Affine>) -> G1Affine {
+        points.fold(G1Projective::identity(), |sum, point| sum + point).into()
+    }
+
+    pub(super) fn sum_g2(points: impl Iterator<Item = G2Affine>) -> G2Affine {
+        points.fold(G2Projective::identity(), |sum, point| sum + point).into()
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl BlsPublicKey {
+    /**
+     * Verifies that `pop` proves possession of the private key
+     *
+     * Finalizers register a proof of possession with their key, which prevents rogue keys
+     * from forging aggregate signatures. The proof signs the packed key itself.
+     */
+    #[must_use]
+    pub fn verify_proof_of_possession(&self, pop: &BlsSignature) -> bool {
+        pop.verify_with_dst(&[(self, &self.data)], curve::POP_DST)
+    }
+
+    /// Sums the keys, so that an aggregate signature of one message verifies against the sum
+    pub fn aggregate(keys: &[BlsPublicKey]) -> Option<BlsPublicKey> {
+        let points = keys.iter().map(|key| curve::g1_from_le(&key.data)).collect::<Option<Vec<_>>>()?;
+        (!points.is_empty()).then(|| BlsPublicKey {
+            data: curve::g1_to_le(&curve::sum_g1(points.into_iter())),
+        })
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl BlsSignature {
+    /// Verifies the signature of `message` against `key`
+    #[must_use]
+    pub fn verify(&self, message: &[u8], key: &BlsPublicKey) -> bool {
+        self.verify_with_dst(&[(key, message)], curve::SIGNATURE_DST)
+    }
+
+    /**
+     * Verifies an aggregate signature of each `(key, message)` pair
+     *
+     * Messages may repeat, as in quorum certificates where finalizers vote on the strong or weak digest.
+     */
+    #[must_use]
+    pub fn aggregate_verify(&self, pairs: &[(&BlsPublicKey, &[u8])]) -> bool {
+        self.verify_with_dst(pairs, curve::SIGNATURE_DST)
+    }
+
+    /// Sums the signatures into an aggregate signature
+    pub fn aggregate(signatures: &[BlsSignature]) -> Option<BlsSignature> {
+        let points = signatures
+            .iter()
+            .map(|sig| curve::g2_from_le(&sig.data))
+            .collect::<Option<Vec<_>>>()?;
+        (!points.is_empty()).then(|| BlsSignature {
+            data: curve::g2_to_le(&curve::sum_g2(points.into_iter())),
+        })
+    }
+
+    fn verify_with_dst(&self, pairs: &[(&BlsPublicKey, &[u8])], dst: &[u8]) -> bool {
+        let Some(sig) = curve::g2_from_le(&self.data) else {
+            return false;
+        };
+        let pairs = pairs
+            .iter()
+            .map(|(key, message)| Some((curve::g1_from_le(&key.data)?, curve::hash_to_g2(message, dst))))
+            .collect::<Option<Vec<_>>>();
+        pairs.is_some_and(|pairs| curve::verify(&pairs, &sig))
+    }
+}
+
+#[cfg(feature = "crypto")]
+mod private_key {
+    use super::*;
+    use bls12_381::{G1Affine, Scalar};
+    use rand_core::CryptoRngCore;
+
+    /// The `BlsPrivateKey` struct represents the BLS12-381 private key of a Savanna finalizer
+    ///
+    /// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/bls_private_key.hpp>
+    ///
+    /// The scalar is non-zero and little-endian, and can only be set through `from_bytes`, `generate`
+    /// or parsing. The string form is `PVT_BLS_` followed by the base64url of the scalar and a
+    /// ripemd160 checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::{BlsPrivateKey, rand_core::OsRng};
+    ///
+    /// let key = BlsPrivateKey::generate(&mut OsRng);
+    /// let public_key = key.public_key();
+    /// assert!(public_key.verify_proof_of_possession(&key.proof_of_possession()));
+    /// assert!(key.sign(b"block").verify(b"block", &public_key));
+    /// ```
+    #[derive(Eq, Clone, PartialEq, Hash)]
+    pub struct BlsPrivateKey {
+        data: [u8; BLS_PRIVATE_KEY_SIZE],
+    }
+
+    impl BlsPrivateKey {
+        /// Creates a private key from its little-endian scalar, which must be non-zero and reduced
+        pub fn from_bytes(data: &[u8]) -> Result<Self, UnpackError> {
+            let invalid = || UnpackError::BadData("invalid BLS private key".to_string());
+            let data: [u8; BLS_PRIVATE_KEY_SIZE] = data.try_into().map_err(|_| invalid())?;
+            let scalar: Option<Scalar> = Scalar::from_bytes(&data).into();
+            if scalar.is_none_or(|scalar| scalar == Scalar::zero()) {
+                return Err(invalid());
+            }
+            Ok(BlsPrivateKey { data })
+        }
+
+        /// Generates a new private key using the caller's random number generator
+        pub fn generate(rng: &mut impl CryptoRngCore) -> Self {
+            loop {
+                let mut wide = [0; 64];
+                rng.fill_bytes(&mut wide);
+                let scalar = Scalar::from_bytes_wide(&wide);
+                if scalar != Scalar::zero() {
+                    return BlsPrivateKey { data: scalar.to_bytes() };
+                }
+            }
+        }
+
+        /// Returns the scalar, little-endian
+        pub fn as_bytes(&self) -> &[u8; BLS_PRIVATE_KEY_SIZE] {
+            &self.data
+        }
+
+        fn scalar(&self) -> Scalar {
+            Scalar::from_bytes(&self.data).expect("validated private key")
+        }
+
+        /// Returns the public key matching this private key
+        #[must_use]
+        pub fn public_key(&self) -> BlsPublicKey {
+            BlsPublicKey {
+                data: curve::g1_to_le(&(G1Affine::generator() * self.scalar()).into()),
+            }
+        }
+
+        /// Signs `message`
+        #[must_use]
+        pub fn sign(&self, message: &[u8]) -> BlsSignature {
+            self.sign_with_dst(message, curve::SIGNATURE_DST)
+        }
+
+        /// Returns the proof of possession of this key, the signature of the packed public key
+        #[must_use]
+        pub fn proof_of_possession(&self) -> BlsSignature {
+            self.sign_with_dst(&self.public_key().data, curve::POP_DST)
+        }
+
+        fn sign_with_dst(&self, message: &[u8], dst: &[u8]) -> BlsSignature {
+            BlsSignature {
+                data: curve::g2_to_le(&(curve::hash_to_g2(message, dst) * self.scalar()).into()),
+            }
+        }
+    }
+
+    impl FromStr for BlsPrivateKey {
+        type Err = ParseError;
+
+        /**
+         * Parse BlsPrivateKey from "PVT_BLS_..." strings, verifying the checksum
+         */
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            decode_bls::<BLS_PRIVATE_KEY_SIZE>(s, "PVT_BLS_")
+                .and_then(|data| BlsPrivateKey::from_bytes(&data).ok())
+                .ok_or_else(|| ParseError::BadPrivateKey(s.to_string()))
+        }
+    }
+
+    impl From<&str> for BlsPrivateKey {
+        fn from(str: &str) -> Self {
+            Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse BlsPrivateKey: {}", e))
+        }
+    }
+
+    impl fmt::Display for BlsPrivateKey {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(&encode_bls("PVT_BLS_", &self.data))
+        }
+    }
+
+    impl fmt::Debug for BlsPrivateKey {
+        /**
+         * Prints the public key, keeping the secret out of logs
+         */
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("BlsPrivateKey")
+                .field("public_key", &self.public_key().to_string())
+                .finish()
+        }
+    }
+
+    impl Pack for BlsPrivateKey {
+        fn pack(&self, out: &mut Vec<u8>) {
+            out.extend_from_slice(&self.data);
+        }
+    }
+
+    impl Unpack for BlsPrivateKey {
+        fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+            BlsPrivateKey::from_bytes(read_bytes(data, BLS_PRIVATE_KEY_SIZE)?)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    crate::serde_helpers::impl_serde_string!(BlsPrivateKey);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec() {
+        let key = BlsPublicKey { data: [7; 96] };
+        let s = key.to_string();
+        assert!(s.starts_with("PUB_BLS_"));
+        assert_eq!(s.len(), 8 + 134);
+        assert_eq!(BlsPublicKey::from_str(&s), Ok(key));
+        assert_eq!(BlsPublicKey::from_str(&format!("{}==", s)), Ok(key));
+        assert_eq!(crate::unpack::<BlsPublicKey>(&key.packed()), Ok(key));
+        assert_eq!(key.packed().len(), BLS_PUBLIC_KEY_SIZE);
+
+        let sig = BlsSignature { data: [9; 192] };
+        let s = sig.to_string();
+        assert!(s.starts_with("SIG_BLS_"));
+        assert_eq!(BlsSignature::from_str(&s), Ok(sig));
+        assert_eq!(crate::unpack::<BlsSignature>(&sig.packed()), Ok(sig));
+        assert_eq!(crate::unpack::<BlsSignature>(&[0; 191]), Err(UnpackError::UnexpectedEof));
+
+        let mut bad_checksum = key.to_string();
+        bad_checksum.replace_range(10..11, if &bad_checksum[10..11] == "A" { "B" } else { "A" });
+        for s in [
+            "",
+            "PUB_BLS_",
+            &bad_checksum,
+            &sig.to_string().replace("SIG_", "PUB_"),
+            &key.to_string().replace("PUB_BLS_", "PUB_K1_"),
+            &format!("{}!", key),
+        ] {
+            assert_eq!(BlsPublicKey::from_str(s), Err(ParseError::BadPublicKey(s.to_string())));
+        }
+        assert_eq!(
+            BlsSignature::from_str(&key.to_string()),
+            Err(ParseError::BadSignature(key.to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let key = BlsPublicKey { data: [7; 96] };
+        let json = format!("\"{}\"", key);
+        assert_eq!(serde_json::to_string(&key).unwrap(), json);
+        assert_eq!(serde_json::from_str::<BlsPublicKey>(&json).unwrap(), key);
+    }
+
+    #[cfg(feature = "crypto")]
+    mod crypto {
+        use super::*;
+        use crate::bytes::{from_hex, to_hex};
+        use bls12_381::{G1Affine, G2Affine};
+        use rand_core::OsRng;
+
+        #[test]
+        fn test_ciphersuite() {
+            // Ethereum consensus BLS vector for the same proof of possession ciphersuite,
+            // whose points are compressed big-endian
+            let mut sk = from_hex("263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3").unwrap();
+            sk.reverse();
+            let key = BlsPrivateKey::from_bytes(&sk).unwrap();
+            assert_eq!(key, BlsPrivateKey::from("PVT_BLS_40AgRuGPJxzyd7nHOg2vhpXywDiJX9h-5BtbL3m9PSYk-VYh"));
+            assert_eq!(
+                key.public_key().to_string(),
+                "PUB_BLS_mven5fYYBnwGTWV3Gwzh1g_iprn_jTZPZOLlc6jE6iIE6g1P5_CJeZG72eyw0ZEExDaf1OV4Qth6nqlOlBdOke6cfAN43QQdwMXkUEvKjcM7GqqtYur8NIA-XVdhcM0X6EV6Og"
+            );
+            let public_key = curve::g1_from_le(&key.public_key().data).unwrap();
+            assert_eq!(
+                to_hex(&public_key.to_compressed()),
+                "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a"
+            );
+            let sig = key.sign(&[0; 32]);
+            assert_eq!(
+                to_hex(&curve::g2_from_le(&sig.data).unwrap().to_compressed()),
+                "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"
+            );
+            assert!(sig.verify(&[0; 32], &key.public_key()));
+        }
+
+        #[test]
+        fn test_encoding() {
+            // the little-endian coordinates are the reversed big-endian ones, with c0 first in G2
+            let g1 = curve::g1_to_le(&G1Affine::generator());
+            let be = G1Affine::generator().to_uncompressed();
+            assert!(g1[..48].iter().eq(be[..48].iter().rev()));
+            assert!(g1[48..].iter().eq(be[48..].iter().rev()));
+            let g2 = curve::g2_to_le(&G2Affine::generator());
+            let be = G2Affine::generator().to_uncompressed();
+            assert!(g2[..48].iter().eq(be[48..96].iter().rev()));
+            assert!(g2[48..96].iter().eq(be[..48].iter().rev()));
+            assert_eq!(curve::g2_from_le(&g2), Some(G2Affine::generator()));
+            assert_eq!(curve::g1_from_le(&[0; 96]), Some(G1Affine::identity()));
+            assert_eq!(curve::g1_from_le(&[1; 96]), None);
+        }
+
+        #[test]
+        fn test_sign() {
+            let key = BlsPrivateKey::generate(&mut OsRng);
+            let public_key = key.public_key();
+            assert_eq!(BlsPrivateKey::from_str(&key.to_string()), Ok(key.clone()));
+            assert_eq!(crate::unpack::<BlsPrivateKey>(&key.packed()), Ok(key.clone()));
+            assert!(!format!("{:?}", key).contains(&key.to_string()));
+
+            let sig = key.sign(b"finality digest");
+            assert!(sig.verify(b"finality digest", &public_key));
+            assert!(!sig.verify(b"other digest", &public_key));
+            assert!(!sig.verify(b"finality digest", &BlsPrivateKey::generate(&mut OsRng).public_key()));
+
+            // a signature is not a proof of possession, and the other way around
+            let pop = key.proof_of_possession();
+            assert!(public_key.verify_proof_of_possession(&pop));
+            assert!(!public_key.verify_proof_of_possession(&key.sign(&public_key.data)));
+            assert!(!pop.verify(&public_key.data, &public_key));
+
+            // invalid points never verify
+            assert!(!BlsSignature { data: [1; 192] }.verify(b"finality digest", &public_key));
+            assert!(!sig.verify(b"finality digest", &BlsPublicKey { data: [0; 96] }));
+            assert!(!BlsSignature { data: [0; 192] }.verify(b"x", &BlsPublicKey { data: [0; 96] }));
+        }
+
+        #[test]
+        fn test_aggregate() {
+            let keys: Vec<BlsPrivateKey> = (0..3).map(|_| BlsPrivateKey::generate(&mut OsRng)).collect();
+            let public_keys: Vec<BlsPublicKey> = keys.iter().map(|key| key.public_key()).collect();
+
+            // finalizers voting on the same digest
+            let sigs: Vec<BlsSignature> = keys.iter().map(|key| key.sign(b"strong")).collect();
+            let aggregate = BlsSignature::aggregate(&sigs).unwrap();
+            let aggregate_key = BlsPublicKey::aggregate(&public_keys).unwrap();
+            assert!(aggregate.verify(b"strong", &aggregate_key));
+            assert!(!aggregate.verify(b"strong", &BlsPublicKey::aggregate(&public_keys[..2]).unwrap()));
+
+            // a quorum certificate mixing strong and weak votes
+            let weak = keys[2].sign(b"weak");
+            let qc = BlsSignature::aggregate(&[sigs[0], sigs[1], weak]).unwrap();
+            let pairs: [(&BlsPublicKey, &[u8]); 3] = [
+                (&public_keys[0], b"strong"),
+                (&public_keys[1], b"strong"),
+                (&public_keys[2], b"weak"),
+            ];
+            assert!(qc.aggregate_verify(&pairs));
+            assert!(!qc.aggregate_verify(&pairs[..2]));
+            assert!(!qc.aggregate_verify(&[]));
+
+            assert_eq!(BlsSignature::aggregate(&[]), None);
+            assert_eq!(BlsPublicKey::aggregate(&[]), None);
+            assert_eq!(BlsSignature::aggregate(&[BlsSignature { data: [1; 192] }]), None);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Pack, ParseError, Unpack, UnpackError};
+
+/// Returns the lowercase hex representation of `bytes`
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parses a hex string, accepting upper and lower case digits
+pub(crate) fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return None;
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok()).collect()
+}
+
+/// The `Bytes` struct represents a variable-length byte array
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/types.hpp>
+///
+/// Packed with a `varuint32` length prefix and written as a hex string in JSON.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Bytes, Pack};
+///
+/// let bytes = Bytes::from("0a0b");
+/// assert_eq!(vec![0x0a, 0x0b], bytes.data);
+/// assert_eq!(vec![0x02, 0x0a, 0x0b], bytes.packed());
+/// assert_eq!("0a0b", bytes.to_string());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+pub struct Bytes {
+    /// The raw bytes
+    pub data: Vec<u8>,
+}
+
+impl Bytes {
+    /// Returns the number of bytes
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if there are no bytes
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the raw bytes
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl FromStr for Bytes {
+    type Err = ParseError;
+
+    /**
+     * Parse Bytes from a hex string
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = from_hex(s).ok_or_else(|| ParseError::BadHex(s.to_string()))?;
+        Ok(Bytes { data })
+    }
+}
+
+impl From<&str> for Bytes {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse bytes: {}", e))
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    #[inline]
+    fn from(data: Vec<u8>) -> Self {
+        Bytes { data }
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    #[inline]
+    fn from(data: &[u8]) -> Self {
+        Bytes { data: data.to_vec() }
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        bytes.data
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl fmt::Display for Bytes {
+    /**
+     * Converts the Bytes into a lowercase hex string
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", to_hex(&self.data))
+    }
+}
+
+impl Pack for Bytes {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.data.pack(out)
+    }
+}
+
+impl Unpack for Bytes {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(Bytes::from(Vec::<u8>::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(Bytes);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Bytes::from_str("").unwrap(), Bytes::default());
+        assert_eq!(Bytes::from_str("00FFab").unwrap().data, vec![0x00, 0xff, 0xab]);
+        assert_eq!(Bytes::from_str("00FFab").unwrap().to_string(), "00ffab");
+        assert_eq!(Bytes::from_str("abc"), Err(ParseError::BadHex("abc".to_string())));
+        assert_eq!(Bytes::from_str("zz"), Err(ParseError::BadHex("zz".to_string())));
+        assert_eq!(Bytes::from_str("éa"), Err(ParseError::BadHex("éa".to_string())));
+    }
+
+    #[test]
+    fn test_pack() {
+        let bytes = Bytes::from("68656c6c6f");
+        assert_eq!(bytes.packed(), [0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f]);
+        assert_eq!(crate::unpack::<Bytes>(&bytes.packed()), Ok(bytes));
+        assert_eq!(crate::unpack::<Bytes>(&[0x02, 0x01]), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let bytes = Bytes::from(vec![0xde, 0xad]);
+        assert_eq!(serde_json::to_string(&bytes).unwrap(), r#""dead""#);
+        assert_eq!(serde_json::from_str::<Bytes>(r#""DEAD""#).unwrap(), bytes);
+        assert!(serde_json::from_str::<Bytes>(r#""dea""#).is_err());
+    }
+}
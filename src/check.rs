@@ -8,11 +8,12 @@
 /// ```
 /// use antelope::check::check;
 ///
-/// #[test]
 /// check(true, "This should not panic");
+/// ```
+///
+/// ```should_panic
+/// use antelope::check::check;
 ///
-/// #[test]
-/// #[should_panic]
 /// check(false, "This should panic");
 /// ```
 pub fn check(predicate: bool, message: &str) {
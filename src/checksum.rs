@@ -0,0 +1,283 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ripemd::Ripemd160;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::bytes::{from_hex, to_hex};
+use crate::{check, Pack, ParseError, Unpack, UnpackError};
+
+macro_rules! impl_checksum {
+    ($t:ident, $size:expr, $abi:literal) => {
+        #[doc = concat!("The `", stringify!($t), "` struct represents a `", $abi, "` digest")]
+        ///
+        /// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/crypto.hpp>
+        ///
+        /// Written as a lowercase hex string. Ordered by comparing the raw bytes,
+        /// like `fc` does in `nodeos`, so sorted digests match on-chain ordering.
+        #[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Hash)]
+        pub struct $t {
+            /// The raw bytes of the digest
+            pub data: [u8; $size],
+        }
+
+        impl $t {
+            /// The size of the digest in bytes
+            pub const SIZE: usize = $size;
+
+            /// Returns the raw bytes of the digest
+            #[inline]
+            #[must_use]
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                &self.data
+            }
+        }
+
+        impl Default for $t {
+            #[inline]
+            fn default() -> Self {
+                $t { data: [0; $size] }
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = ParseError;
+
+            /**
+             * Parse a digest from a hex string
+             */
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let data = from_hex(s)
+                    .and_then(|data| data.try_into().ok())
+                    .ok_or_else(|| ParseError::BadChecksum(s.to_string()))?;
+                Ok($t { data })
+            }
+        }
+
+        impl From<&str> for $t {
+            fn from(str: &str) -> Self {
+                Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse {}: {}", $abi, e))
+            }
+        }
+
+        impl From<[u8; $size]> for $t {
+            #[inline]
+            fn from(data: [u8; $size]) -> Self {
+                $t { data }
+            }
+        }
+
+        impl TryFrom<&[u8]> for $t {
+            type Error = ParseError;
+
+            fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+                let data = data.try_into().map_err(|_| ParseError::BadChecksum(to_hex(data)))?;
+                Ok($t { data })
+            }
+        }
+
+        impl AsRef<[u8]> for $t {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                &self.data
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", to_hex(&self.data))
+            }
+        }
+
+        impl Pack for $t {
+            #[inline]
+            fn pack(&self, out: &mut Vec<u8>) {
+                self.data.pack(out)
+            }
+        }
+
+        impl Unpack for $t {
+            #[inline]
+            fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+                Ok($t::from(<[u8; $size]>::unpack(data)?))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        crate::serde_helpers::impl_serde_string!($t);
+    };
+}
+
+impl_checksum!(Checksum160, 20, "checksum160");
+impl_checksum!(Checksum256, 32, "checksum256");
+impl_checksum!(Checksum512, 64, "checksum512");
+
+impl Checksum160 {
+    /// Hashes `data` with SHA-1
+    #[must_use]
+    pub fn sha1(data: &[u8]) -> Self {
+        Checksum160::from(<[u8; 20]>::from(Sha1::digest(data)))
+    }
+
+    /// Hashes `data` with RIPEMD-160
+    #[must_use]
+    pub fn ripemd160(data: &[u8]) -> Self {
+        Checksum160::from(<[u8; 20]>::from(Ripemd160::digest(data)))
+    }
+}
+
+impl Checksum256 {
+    /// Hashes `data` with SHA-256
+    #[must_use]
+    pub fn sha256(data: &[u8]) -> Self {
+        Checksum256::from(<[u8; 32]>::from(Sha256::digest(data)))
+    }
+}
+
+impl Checksum512 {
+    /// Hashes `data` with SHA-512
+    #[must_use]
+    pub fn sha512(data: &[u8]) -> Self {
+        Checksum512::from(<[u8; 64]>::from(Sha512::digest(data)))
+    }
+}
+
+/// Hashes `data` using SHA-1
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/crypto.hpp>
+#[must_use]
+pub fn sha1(data: &[u8]) -> Checksum160 {
+    Checksum160::sha1(data)
+}
+
+/// Hashes `data` using SHA-256
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/crypto.hpp>
+///
+/// # Examples
+///
+/// ```
+/// use antelope::sha256;
+///
+/// assert_eq!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad", sha256(b"abc").to_string());
+/// ```
+#[must_use]
+pub fn sha256(data: &[u8]) -> Checksum256 {
+    Checksum256::sha256(data)
+}
+
+/// Hashes `data` using SHA-512
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/crypto.hpp>
+#[must_use]
+pub fn sha512(data: &[u8]) -> Checksum512 {
+    Checksum512::sha512(data)
+}
+
+/// Hashes `data` using RIPEMD-160
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/crypto.hpp>
+#[must_use]
+pub fn ripemd160(data: &[u8]) -> Checksum160 {
+    Checksum160::ripemd160(data)
+}
+
+/// Tests if the SHA-1 hash of `data` matches `hash`, and panics if it does not
+pub fn assert_sha1(data: &[u8], hash: &Checksum160) {
+    check(sha1(data) == *hash, "hash mismatch");
+}
+
+/// Tests if the SHA-256 hash of `data` matches `hash`, and panics if it does not
+pub fn assert_sha256(data: &[u8], hash: &Checksum256) {
+    check(sha256(data) == *hash, "hash mismatch");
+}
+
+/// Tests if the SHA-512 hash of `data` matches `hash`, and panics if it does not
+pub fn assert_sha512(data: &[u8], hash: &Checksum512) {
+    check(sha512(data) == *hash, "hash mismatch");
+}
+
+/// Tests if the RIPEMD-160 hash of `data` matches `hash`, and panics if it does not
+pub fn assert_ripemd160(data: &[u8], hash: &Checksum160) {
+    check(ripemd160(data) == *hash, "hash mismatch");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn test_from_str() {
+        let checksum = Checksum256::from(HASH);
+        assert_eq!(checksum.data[0], 0xe3);
+        assert_eq!(checksum.data[31], 0x55);
+        assert_eq!(checksum.to_string(), HASH);
+        assert_eq!(Checksum256::from_str(&HASH.to_uppercase()), Ok(checksum));
+        assert_eq!(Checksum160::from_str(HASH), Err(ParseError::BadChecksum(HASH.to_string())));
+        assert_eq!(Checksum256::from_str("xyz"), Err(ParseError::BadChecksum("xyz".to_string())));
+        assert_eq!(Checksum512::default().to_string(), "0".repeat(128));
+        assert_eq!(Checksum160::try_from(&[1_u8; 20][..]), Ok(Checksum160::from([1; 20])));
+        assert!(Checksum160::try_from(&[1_u8; 19][..]).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(sha256(b"").to_string(), HASH);
+        assert_eq!(sha1(b"abc").to_string(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(ripemd160(b"abc").to_string(), "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+        assert_eq!(
+            sha512(b"abc").to_string(),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(Checksum256::sha256(b"abc"), sha256(b"abc"));
+        assert_sha256(b"", &Checksum256::from(HASH));
+        assert_ripemd160(b"abc", &ripemd160(b"abc"));
+    }
+
+    #[test]
+    #[should_panic(expected = "hash mismatch")]
+    fn test_assert_hash() {
+        assert_sha1(b"abc", &Checksum160::default());
+    }
+
+    #[test]
+    fn test_ordering() {
+        // byte-wise, like fc::sha256::operator<
+        let mut sorted = [
+            Checksum256::from("0100000000000000000000000000000000000000000000000000000000000000"),
+            Checksum256::from("0000000000000000000000000000000000000000000000000000000000000002"),
+            Checksum256::from("00000000000000000000000000000000000000000000000000000000000000ff"),
+        ];
+        sorted.sort();
+        assert_eq!(
+            sorted.iter().map(|c| c.data[0] as u32 + c.data[31] as u32).collect::<Vec<_>>(),
+            vec![2, 255, 1]
+        );
+    }
+
+    #[test]
+    fn test_pack() {
+        let checksum = Checksum256::from(HASH);
+        assert_eq!(checksum.packed(), checksum.data);
+        assert_eq!(crate::unpack::<Checksum256>(&checksum.packed()), Ok(checksum));
+        assert_eq!(crate::unpack::<Checksum512>(&checksum.packed()), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let checksum = Checksum160::from("0102030405060708090a0b0c0d0e0f1011121314");
+        assert_eq!(
+            serde_json::to_string(&checksum).unwrap(),
+            r#""0102030405060708090a0b0c0d0e0f1011121314""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Checksum160>(r#""0102030405060708090a0b0c0d0e0f1011121314""#).unwrap(),
+            checksum
+        );
+        assert!(serde_json::from_str::<Checksum160>(r#""0102""#).is_err());
+    }
+}
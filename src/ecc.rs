@@ -0,0 +1,68 @@
+//! ECDSA and ECDH primitives over secp256k1 (`K1`) and secp256r1 (`R1`), on compact `r || s` signatures.
+
+macro_rules! impl_curve {
+    ($name:ident, $curve:ident :: $c:ident) => {
+        pub(crate) mod $name {
+            use ecdsa::hazmat::SignPrimitive;
+            use ecdsa::signature::hazmat::PrehashVerifier;
+            use ecdsa::RecoveryId;
+            use $curve::SecretKey;
+
+            type Signature = ecdsa::Signature<$curve::$c>;
+            type VerifyingKey = ecdsa::VerifyingKey<$curve::$c>;
+
+            /**
+             * Signs `digest` with a RFC 6979 nonce salted with `ad`
+             *
+             * Returns the low-S compact signature and its recovery id.
+             */
+            pub(crate) fn sign(secret: &[u8; 32], digest: &[u8; 32], ad: &[u8]) -> ([u8; 64], u8) {
+                let secret = SecretKey::from_bytes(secret.into()).expect("validated private key");
+                let (sig, recid) = secret
+                    .to_nonzero_scalar()
+                    .as_ref()
+                    .try_sign_prehashed_rfc6979::<sha2::Sha256>(digest.into(), ad)
+                    .expect("valid nonce");
+                let recid = recid.expect("recovery id");
+                let (sig, recid) = match sig.normalize_s() {
+                    Some(low) => (low, RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced())),
+                    None => (sig, recid),
+                };
+                (sig.to_bytes().into(), recid.to_byte())
+            }
+
+            /// Verifies the compact signature `rs` of `digest` against the SEC1 encoded `point`
+            pub(crate) fn verify(point: &[u8], digest: &[u8; 32], rs: &[u8]) -> bool {
+                let (Ok(key), Ok(sig)) = (VerifyingKey::from_sec1_bytes(point), Signature::from_slice(rs)) else {
+                    return false;
+                };
+                let sig = sig.normalize_s().unwrap_or(sig);
+                key.verify_prehash(digest, &sig).is_ok()
+            }
+
+            /// Recovers the compressed public key that produced the compact signature `rs` of `digest`
+            pub(crate) fn recover(digest: &[u8; 32], rs: &[u8], recid: u8) -> Option<Vec<u8>> {
+                let sig = Signature::from_slice(rs).ok()?;
+                let recid = RecoveryId::from_byte(recid)?;
+                // negating s negates the nonce point, so its y parity flips
+                let (sig, recid) = match sig.normalize_s() {
+                    Some(low) => (low, RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced())),
+                    None => (sig, recid),
+                };
+                let key = VerifyingKey::recover_from_prehash(digest, &sig, recid).ok()?;
+                Some(key.to_encoded_point(true).as_bytes().to_vec())
+            }
+
+            /// Returns the x coordinate of `secret` times the SEC1 encoded `point`
+            pub(crate) fn shared_secret(secret: &[u8; 32], point: &[u8]) -> Option<[u8; 32]> {
+                let secret = SecretKey::from_bytes(secret.into()).expect("validated private key");
+                let public = $curve::PublicKey::from_sec1_bytes(point).ok()?;
+                let shared = $curve::ecdh::diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+                Some((*shared.raw_secret_bytes()).into())
+            }
+        }
+    };
+}
+
+impl_curve!(k1, k256::Secp256k1);
+impl_curve!(r1, p256::NistP256);
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand_core::CryptoRngCore;
+
+use crate::{
+    aes_cbc, pack, sha256, sha512, unpack, Checksum512, CryptoError, Pack, ParseError, PrivateKey, PublicKey, Unpack, UnpackError,
+};
+
+/// The prefix of encrypted messages in their string form, as used in memos
+pub const ENCRYPTED_MESSAGE_PREFIX: char = '#';
+
+/// The `EncryptedMessage` struct represents a message encrypted between two key pairs
+///
+/// Reference: <https://github.com/EOSIO/eosjs-ecc/blob/master/src/aes.js>
+///
+/// The encryption follows eosjs-ecc `Aes.encrypt`: the key is `sha512(nonce || shared_secret)` with
+/// the nonce as a little-endian `u64`, the message is encrypted with AES-256-CBC, and `checksum` is the
+/// first 4 bytes of the sha256 of the key, as a little-endian `u32`. Both public keys are kept so either
+/// party can decrypt. The string form is `#` followed by the base58 of the packed message.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{EncryptedMessage, PrivateKey};
+///
+/// let exchange = PrivateKey::from("5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3");
+/// let user = PrivateKey::from("PVT_K1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2FtepK2");
+///
+/// let memo = EncryptedMessage::encrypt_with_nonce(&user, &exchange.public_key(), b"account 7", 1).unwrap();
+/// let memo = EncryptedMessage::from(memo.to_string().as_str());
+/// assert_eq!(b"account 7".to_vec(), memo.decrypt(&exchange).unwrap());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq)]
+pub struct EncryptedMessage {
+    /// The public key of the sender
+    pub from: PublicKey,
+    /// The public key of the recipient
+    pub to: PublicKey,
+    /// The nonce mixed into the encryption key, unique per message
+    pub nonce: u64,
+    /// The checksum of the encryption key
+    pub checksum: u32,
+    /// The AES-256-CBC encrypted message
+    pub message: Vec<u8>,
+}
+
+impl EncryptedMessage {
+    /// Encrypts `message` from `from` to `to` with a random nonce
+    pub fn encrypt(from: &PrivateKey, to: &PublicKey, message: &[u8], rng: &mut impl CryptoRngCore) -> Result<Self, CryptoError> {
+        EncryptedMessage::encrypt_with_nonce(from, to, message, rng.next_u64())
+    }
+
+    /**
+     * Encrypts `message` from `from` to `to` with the given `nonce`
+     *
+     * Reusing a nonce between the same keys reuses the encryption key and IV.
+     */
+    pub fn encrypt_with_nonce(from: &PrivateKey, to: &PublicKey, message: &[u8], nonce: u64) -> Result<Self, CryptoError> {
+        let key = encryption_key(&from.shared_secret(to)?, nonce);
+        Ok(EncryptedMessage {
+            from: from.public_key(),
+            to: to.clone(),
+            nonce,
+            checksum: key_checksum(&key),
+            message: aes_cbc::encrypt(&key, message),
+        })
+    }
+
+    /// Decrypts the message with the private key of either the sender or the recipient
+    pub fn decrypt(&self, key: &PrivateKey) -> Result<Vec<u8>, CryptoError> {
+        let public_key = key.public_key();
+        let other = if public_key == self.from {
+            &self.to
+        } else if public_key == self.to {
+            &self.from
+        } else {
+            return Err(CryptoError::Decrypt("key is not a party of the message".to_string()));
+        };
+        let key = encryption_key(&key.shared_secret(other)?, self.nonce);
+        if key_checksum(&key) != self.checksum {
+            return Err(CryptoError::Decrypt("invalid checksum".to_string()));
+        }
+        aes_cbc::decrypt(&key, &self.message).ok_or_else(|| CryptoError::Decrypt("invalid padding".to_string()))
+    }
+}
+
+/// Returns the AES key and IV for `nonce`, `sha512(nonce || shared_secret)`
+fn encryption_key(shared_secret: &Checksum512, nonce: u64) -> Checksum512 {
+    sha512(&[nonce.to_le_bytes().as_slice(), &shared_secret.data].concat())
+}
+
+/// Returns the first 4 bytes of the sha256 of the encryption key, little-endian
+fn key_checksum(key: &Checksum512) -> u32 {
+    let hash = sha256(&key.data);
+    u32::from_le_bytes([hash.data[0], hash.data[1], hash.data[2], hash.data[3]])
+}
+
+impl FromStr for EncryptedMessage {
+    type Err = ParseError;
+
+    /**
+     * Parse EncryptedMessage from "#..." strings
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::BadEncryptedMessage(s.to_string());
+        let encoded = s.strip_prefix(ENCRYPTED_MESSAGE_PREFIX).ok_or_else(err)?;
+        let bytes = bs58::decode(encoded).into_vec().map_err(|_| err())?;
+        unpack(&bytes).map_err(|_| err())
+    }
+}
+
+impl From<&str> for EncryptedMessage {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse encrypted message: {}", e))
+    }
+}
+
+impl fmt::Display for EncryptedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", ENCRYPTED_MESSAGE_PREFIX, bs58::encode(pack(self)).into_string())
+    }
+}
+
+impl Pack for EncryptedMessage {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.from.pack(out);
+        self.to.pack(out);
+        self.nonce.pack(out);
+        self.checksum.pack(out);
+        self.message.pack(out);
+    }
+}
+
+impl Unpack for EncryptedMessage {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(EncryptedMessage {
+            from: PublicKey::unpack(data)?,
+            to: PublicKey::unpack(data)?,
+            nonce: u64::unpack(data)?,
+            checksum: u32::unpack(data)?,
+            message: Vec::unpack(data)?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(EncryptedMessage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyType;
+    use rand_core::OsRng;
+
+    const ALICE: &str = "5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3";
+    /// The K1 key with scalar 0x0102..20
+    const BOB: &str = "PVT_K1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2FtepK2";
+
+    fn bob() -> PrivateKey {
+        PrivateKey::from(BOB)
+    }
+
+    #[test]
+    fn test_encrypt() {
+        // vector computed with an independent secp256k1 and AES implementation
+        let alice = PrivateKey::from(ALICE);
+        let msg =
+            EncryptedMessage::encrypt_with_nonce(&alice, &bob().public_key(), b"deposit 42 to account 7", 1234567890123456789).unwrap();
+        assert_eq!(msg.checksum, 977433942);
+        assert_eq!(
+            crate::bytes::to_hex(&msg.message),
+            "3fefc0c8722b3f36b9ae5ec72952d7f754697dcdff5a9a291120f50b5d181392"
+        );
+        assert_eq!(msg.decrypt(&alice).unwrap(), b"deposit 42 to account 7");
+        assert_eq!(msg.decrypt(&bob()).unwrap(), b"deposit 42 to account 7");
+
+        let s = msg.to_string();
+        assert!(s.starts_with('#'));
+        assert_eq!(EncryptedMessage::from_str(&s), Ok(msg.clone()));
+        assert_eq!(unpack::<EncryptedMessage>(&pack(&msg)), Ok(msg));
+
+        let msg = EncryptedMessage::encrypt(&bob(), &alice.public_key(), b"", &mut OsRng).unwrap();
+        assert_eq!(msg.message.len(), 16);
+        assert_eq!(msg.decrypt(&alice).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_errors() {
+        let alice = PrivateKey::from(ALICE);
+        let mut msg = EncryptedMessage::encrypt_with_nonce(&alice, &bob().public_key(), b"memo", 7).unwrap();
+        let eve = PrivateKey::generate(KeyType::K1, &mut OsRng);
+        assert_eq!(
+            msg.decrypt(&eve),
+            Err(CryptoError::Decrypt("key is not a party of the message".to_string()))
+        );
+        msg.nonce = 8;
+        assert_eq!(msg.decrypt(&bob()), Err(CryptoError::Decrypt("invalid checksum".to_string())));
+
+        let r1 = PrivateKey::from("PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx");
+        assert_eq!(
+            EncryptedMessage::encrypt_with_nonce(&r1, &bob().public_key(), b"memo", 7),
+            Err(CryptoError::UnsupportedKeyType(KeyType::K1))
+        );
+
+        for s in ["", "memo", "#", "#0OIl", "#3mJr7AoUXx2Wqd"] {
+            assert_eq!(EncryptedMessage::from_str(s), Err(ParseError::BadEncryptedMessage(s.to_string())));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{Checksum256, KeyType, Name, TimePointSec};
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum ParseError {
     BadFormat,
@@ -7,6 +9,16 @@ pub enum ParseError {
     BadPrecision(String),
     BadAsset(String),
     BadName(String),
+    BadTimePoint(String),
+    BadHex(String),
+    BadChecksum(String),
+    BadPublicKey(String),
+    BadSignature(String),
+    BadPrivateKey(String),
+    BadEncryptedMessage(String),
+    BadMnemonic(String),
+    BadDerivationPath(String),
+    BadPermissionLevel(String),
 }
 
 impl std::fmt::Display for ParseError {
@@ -19,6 +31,182 @@ impl std::fmt::Display for ParseError {
             ParseError::BadPrecision(s) => write!(f, "bad precision: {}", s),
             ParseError::BadAsset(s) => write!(f, "bad asset: {}", s),
             ParseError::BadName(s) => write!(f, "bad name: {}", s),
+            ParseError::BadTimePoint(s) => write!(f, "bad time point: {}", s),
+            ParseError::BadHex(s) => write!(f, "bad hex: {}", s),
+            ParseError::BadChecksum(s) => write!(f, "bad checksum: {}", s),
+            ParseError::BadPublicKey(s) => write!(f, "bad public key: {}", s),
+            ParseError::BadSignature(s) => write!(f, "bad signature: {}", s),
+            ParseError::BadPrivateKey(s) => write!(f, "bad private key: {}", s),
+            ParseError::BadEncryptedMessage(s) => write!(f, "bad encrypted message: {}", s),
+            ParseError::BadMnemonic(s) => write!(f, "bad mnemonic: {}", s),
+            ParseError::BadDerivationPath(s) => write!(f, "bad derivation path: {}", s),
+            ParseError::BadPermissionLevel(s) => write!(f, "bad permission level: {}", s),
         }
     }
 }
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum UnpackError {
+    UnexpectedEof,
+    TrailingBytes(usize),
+    BadVarint,
+    BadBool(u8),
+    BadUtf8,
+    BadData(String),
+}
+
+impl std::fmt::Display for UnpackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            UnpackError::UnexpectedEof => write!(f, "unexpected end of data"),
+            UnpackError::TrailingBytes(n) => write!(f, "{} trailing bytes after unpack", n),
+            UnpackError::BadVarint => write!(f, "bad varint"),
+            UnpackError::BadBool(b) => write!(f, "bad bool: {}", b),
+            UnpackError::BadUtf8 => write!(f, "bad utf8 string"),
+            UnpackError::BadData(s) => write!(f, "bad data: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for UnpackError {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AbiError {
+    BadJson(String),
+    UnsupportedVersion(String),
+    BadDefinition(String),
+    UnknownType(String),
+    UnknownAction(String),
+    BadValue(String),
+    BadBinary(UnpackError),
+    RecursionLimit,
+}
+
+impl std::fmt::Display for AbiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AbiError::BadJson(s) => write!(f, "bad abi json: {}", s),
+            AbiError::UnsupportedVersion(s) => write!(f, "unsupported abi version: {}", s),
+            AbiError::BadDefinition(s) => write!(f, "bad abi definition: {}", s),
+            AbiError::UnknownType(s) => write!(f, "unknown type: {}", s),
+            AbiError::UnknownAction(s) => write!(f, "unknown action: {}", s),
+            AbiError::BadValue(s) => write!(f, "bad value: {}", s),
+            AbiError::BadBinary(e) => write!(f, "bad binary: {}", e),
+            AbiError::RecursionLimit => write!(f, "recursive definition too deep"),
+        }
+    }
+}
+
+impl std::error::Error for AbiError {}
+
+impl From<UnpackError> for AbiError {
+    fn from(e: UnpackError) -> Self {
+        AbiError::BadBinary(e)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CryptoError {
+    NonCanonicalSignature,
+    BadRecoveryId(u8),
+    RecoveryFailed,
+    UnsupportedKeyType(KeyType),
+    WebAuthn(String),
+    InvalidPublicKey,
+    Decrypt(String),
+}
+
+impl std::fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CryptoError::NonCanonicalSignature => write!(f, "signature is not canonical"),
+            CryptoError::BadRecoveryId(b) => write!(f, "bad recovery id: {}", b),
+            CryptoError::RecoveryFailed => write!(f, "unable to reconstruct public key from signature"),
+            CryptoError::UnsupportedKeyType(t) => write!(f, "unsupported key type: {}", t),
+            CryptoError::WebAuthn(s) => write!(f, "webauthn: {}", s),
+            CryptoError::InvalidPublicKey => write!(f, "public key is not a valid curve point"),
+            CryptoError::Decrypt(s) => write!(f, "unable to decrypt: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for CryptoError {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum WalletError {
+    Locked,
+    InvalidPassword,
+    KeyExists(String),
+    KeyNotFound(String),
+    BadFile(String),
+    Io(String),
+}
+
+impl std::fmt::Display for WalletError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            WalletError::Locked => write!(f, "wallet is locked"),
+            WalletError::InvalidPassword => write!(f, "invalid password for wallet"),
+            WalletError::KeyExists(s) => write!(f, "key already in wallet: {}", s),
+            WalletError::KeyNotFound(s) => write!(f, "key not in wallet: {}", s),
+            WalletError::BadFile(s) => write!(f, "bad wallet file: {}", s),
+            WalletError::Io(s) => write!(f, "wallet io error: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for WalletError {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ProofError {
+    Expired(TimePointSec),
+    ChainMismatch(Checksum256),
+    ScopeMismatch(Name),
+    InvalidSignature,
+}
+
+impl std::fmt::Display for ProofError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ProofError::Expired(t) => write!(f, "proof expired at {}", t),
+            ProofError::ChainMismatch(c) => write!(f, "proof is for chain {}", c),
+            ProofError::ScopeMismatch(n) => write!(f, "proof is for scope {}", n),
+            ProofError::InvalidSignature => write!(f, "proof is not signed by the key"),
+        }
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AuthorityError {
+    TooManyEntries(usize),
+    UnsortedKeys,
+    UnsortedAccounts,
+    UnsortedWaits,
+    ZeroWeight,
+    ZeroWait,
+    WaitTooLong(u32),
+    ZeroThreshold,
+    ThresholdUnreachable(u32),
+}
+
+impl std::fmt::Display for AuthorityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AuthorityError::TooManyEntries(n) => write!(f, "authority has too many entries: {}", n),
+            AuthorityError::UnsortedKeys => write!(f, "authority keys are not sorted and unique"),
+            AuthorityError::UnsortedAccounts => write!(f, "authority accounts are not sorted and unique"),
+            AuthorityError::UnsortedWaits => write!(f, "authority waits are not sorted and unique"),
+            AuthorityError::ZeroWeight => write!(f, "authority has a zero weight"),
+            AuthorityError::ZeroWait => write!(f, "authority has a zero second wait"),
+            AuthorityError::WaitTooLong(s) => write!(f, "authority wait too long: {} seconds", s),
+            AuthorityError::ZeroThreshold => write!(f, "authority threshold is zero"),
+            AuthorityError::ThresholdUnreachable(t) => write!(f, "authority threshold {} exceeds the total weight", t),
+        }
+    }
+}
+
+impl std::error::Error for AuthorityError {}
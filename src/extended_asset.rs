@@ -1,4 +1,4 @@
-use crate::{check, Asset, ExtendedSymbol, Name, ParseError};
+use crate::{check, Asset, ExtendedSymbol, Name, Pack, ParseError, Unpack, UnpackError};
 use std::str::FromStr;
 
 /// The `ExtendedAsset` struct represents an extended asset
@@ -14,6 +14,7 @@ use std::str::FromStr;
 /// assert_eq!(10000, ext_asset.quantity.amount);
 /// ```
 #[derive(Eq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtendedAsset {
     /**
      * The asset
@@ -132,7 +133,6 @@ impl From<&str> for ExtendedAsset {
 
 impl AsRef<ExtendedAsset> for ExtendedAsset {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &ExtendedAsset {
         self
     }
@@ -328,6 +328,21 @@ impl std::ops::Div<ExtendedAsset> for ExtendedAsset {
     }
 }
 
+impl Pack for ExtendedAsset {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.quantity.pack(out);
+        self.contract.pack(out);
+    }
+}
+
+impl Unpack for ExtendedAsset {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let quantity = Asset::unpack(data)?;
+        let contract = Name::unpack(data)?;
+        Ok(ExtendedAsset { quantity, contract })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -635,4 +650,24 @@ mod tests {
             Err(ParseError::BadName(String::from("contr9ct")))
         );
     }
+
+    #[test]
+    fn test_pack() {
+        let ext_asset = ExtendedAsset::from("1.0000 EOS@eosio.token");
+        let bytes = [
+            0x10, 0x27, 0, 0, 0, 0, 0, 0, 0x04, 0x45, 0x4f, 0x53, 0, 0, 0, 0, 0x00, 0xa6, 0x82, 0x34, 0x03, 0xea, 0x30, 0x55,
+        ];
+        assert_eq!(ext_asset.packed(), bytes);
+        assert_eq!(crate::unpack::<ExtendedAsset>(&bytes), Ok(ext_asset));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = ExtendedAsset::from("1.0000 EOS@eosio.token");
+        let json = r#"{"quantity":"1.0000 EOS","contract":"eosio.token"}"#;
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ExtendedAsset>(json).unwrap(), value);
+        assert!(serde_json::from_str::<ExtendedAsset>(r#"{"quantity":"1.0000 EOS"}"#).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Name, ParseError, Symbol};
+use crate::{Name, Pack, ParseError, Symbol, Unpack, UnpackError};
 use std::cmp::{Ord, PartialEq, PartialOrd};
 use std::convert::From;
 use std::fmt::{Display, Formatter};
@@ -18,6 +18,7 @@ use std::str::FromStr;
 /// assert_eq!("token", ext_sym.get_contract().to_string());
 /// ```
 #[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct ExtendedSymbol {
     contract: Name,
     sym: Symbol,
@@ -102,7 +103,6 @@ impl From<&str> for ExtendedSymbol {
 
 impl AsRef<ExtendedSymbol> for ExtendedSymbol {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &ExtendedSymbol {
         self
     }
@@ -110,12 +110,38 @@ impl AsRef<ExtendedSymbol> for ExtendedSymbol {
 
 impl From<ExtendedSymbol> for bool {
     #[inline]
-    #[must_use]
     fn from(ext_sym: ExtendedSymbol) -> Self {
         ext_sym.contract.raw() != 0 && ext_sym.sym.raw() != 0
     }
 }
 
+impl Pack for ExtendedSymbol {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.sym.pack(out);
+        self.contract.pack(out);
+    }
+}
+
+impl Unpack for ExtendedSymbol {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let sym = Symbol::unpack(data)?;
+        let contract = Name::unpack(data)?;
+        Ok(ExtendedSymbol { sym, contract })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExtendedSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ExtendedSymbol", 2)?;
+        state.serialize_field("sym", &self.sym)?;
+        state.serialize_field("contract", &self.contract)?;
+        state.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,4 +238,21 @@ mod tests {
         assert_eq!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s2), true);
         assert_eq!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s3), true);
     }
+
+    #[test]
+    fn test_pack() {
+        let ext_sym = ExtendedSymbol::from("4,EOS@eosio.token");
+        let bytes = [0x04, 0x45, 0x4f, 0x53, 0, 0, 0, 0, 0x00, 0xa6, 0x82, 0x34, 0x03, 0xea, 0x30, 0x55];
+        assert_eq!(crate::Pack::packed(&ext_sym), bytes);
+        assert_eq!(crate::unpack::<ExtendedSymbol>(&bytes), Ok(ext_sym));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = ExtendedSymbol::from("4,EOS@eosio.token");
+        let json = r#"{"sym":"4,EOS","contract":"eosio.token"}"#;
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ExtendedSymbol>(json).unwrap(), value);
+    }
 }
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::bytes::{from_hex, to_hex};
+use crate::{Pack, ParseError, Unpack, UnpackError};
+
+/// The `Float128` struct represents an IEEE 754 quadruple-precision float
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/types.hpp>
+///
+/// Rust has no native 128-bit float, so the value is kept as its 16 little-endian bytes
+/// and written as a `0x` prefixed hex string, like `nodeos` does.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::Float128;
+///
+/// let one = Float128::from("0x0000000000000000000000000000ff3f");
+/// assert_eq!(0x3f, one.data[15]);
+/// assert_eq!("0x0000000000000000000000000000ff3f", one.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, Default, Hash)]
+pub struct Float128 {
+    /// The little-endian bytes of the float
+    pub data: [u8; 16],
+}
+
+impl FromStr for Float128 {
+    type Err = ParseError;
+
+    /**
+     * Parse Float128 from a `0x` prefixed string of 32 hex digits
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s
+            .strip_prefix("0x")
+            .and_then(from_hex)
+            .and_then(|data| data.try_into().ok())
+            .ok_or_else(|| ParseError::BadHex(s.to_string()))?;
+        Ok(Float128 { data })
+    }
+}
+
+impl From<&str> for Float128 {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse float128: {}", e))
+    }
+}
+
+impl From<[u8; 16]> for Float128 {
+    #[inline]
+    fn from(data: [u8; 16]) -> Self {
+        Float128 { data }
+    }
+}
+
+impl fmt::Display for Float128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x{}", to_hex(&self.data))
+    }
+}
+
+impl Pack for Float128 {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.data.pack(out)
+    }
+}
+
+impl Unpack for Float128 {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(Float128::from(<[u8; 16]>::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(Float128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Float128::from_str("0x00000000000000000000000000000000").unwrap(),
+            Float128::default()
+        );
+        assert_eq!(Float128::from_str("0x0000000000000000000000000000FF3F").unwrap().data[14], 0xff);
+        assert_eq!(
+            Float128::from_str("00000000000000000000000000000000"),
+            Err(ParseError::BadHex("00000000000000000000000000000000".to_string()))
+        );
+        assert_eq!(Float128::from_str("0x00"), Err(ParseError::BadHex("0x00".to_string())));
+    }
+
+    #[test]
+    fn test_pack() {
+        let value = Float128::from("0x0102030405060708090a0b0c0d0e0f10");
+        assert_eq!(value.packed(), (1..=16).collect::<Vec<u8>>());
+        assert_eq!(crate::unpack::<Float128>(&value.packed()), Ok(value));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = Float128::from("0x0000000000000000000000000000ff3f");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""0x0000000000000000000000000000ff3f""#);
+        assert_eq!(
+            serde_json::from_str::<Float128>(r#""0x0000000000000000000000000000ff3f""#).unwrap(),
+            value
+        );
+    }
+}
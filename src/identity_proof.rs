@@ -0,0 +1,278 @@
+use crate::serializer::impl_pack_struct;
+use crate::{
+    sha256, Action, Checksum256, CryptoError, Name, PermissionLevel, PrivateKey, ProofError, PublicKey, Signature, TimePointSec,
+    Transaction, TransactionHeader,
+};
+
+/// The prefix of signed messages, so they can never be valid transaction or block signatures
+pub const SIGNED_MESSAGE_PREFIX: &[u8] = b"Antelope Signed Message:\n";
+
+/**
+ * Returns the digest signed for an arbitrary message on `chain_id`
+ *
+ * The digest is `sha256(SIGNED_MESSAGE_PREFIX || chain_id || data)`. Transaction digests start
+ * with the chain id instead, so a message signature can't authorize a transaction.
+ *
+ * This scheme is specific to this crate, wallets don't produce such signatures. Use
+ * [`IdentityProof`] to verify wallet logins.
+ */
+#[must_use]
+pub fn message_digest(chain_id: &Checksum256, data: &[u8]) -> Checksum256 {
+    sha256(&[SIGNED_MESSAGE_PREFIX, &chain_id.data, data].concat())
+}
+
+/// The `IdentityProof` struct represents the login proof of a signing request identity
+///
+/// Reference: <https://github.com/wharfkit/signing-request/blob/master/src/identity-proof.ts>
+///
+/// Wallets answering an ESR identity request sign a transaction that can never execute: a single
+/// `identity` action on the empty account, authorized by `signer`, with `expiration` set and a zero
+/// reference block. `scope` names the application requesting the login, so a proof made for one
+/// application is rejected by another.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Checksum256, IdentityProof, Name, PermissionLevel, PrivateKey, TimePointSec};
+///
+/// let chain_id = Checksum256::from("aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906");
+/// let key = PrivateKey::from("5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3");
+/// let expiration = TimePointSec::from_iso_string("2024-01-01T00:05:00");
+///
+/// let proof = IdentityProof::sign(&key, chain_id, Name::from("myapp"), PermissionLevel::from("alice@active"), expiration);
+/// let now = TimePointSec::from_iso_string("2024-01-01T00:00:00");
+/// assert_eq!(Ok(()), proof.verify(&key.public_key(), &chain_id, Name::from("myapp"), now));
+/// assert!(proof.verify(&key.public_key(), &chain_id, Name::from("myapp"), expiration).is_err());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentityProof {
+    /// The chain the account lives on
+    pub chain_id: Checksum256,
+    /// The application the proof is made for
+    pub scope: Name,
+    /// The time after which the proof is no longer valid
+    pub expiration: TimePointSec,
+    /// The account permission holding the signing key
+    pub signer: PermissionLevel,
+    /// The signature of the proof transaction
+    pub signature: Signature,
+}
+
+impl_pack_struct!(IdentityProof {
+    chain_id,
+    scope,
+    expiration,
+    signer,
+    signature
+});
+
+/// The arguments of the `identity` action, `identity_v3` in ESR
+#[derive(Eq, Clone, Debug, PartialEq)]
+struct Identity {
+    scope: Name,
+    permission: Option<PermissionLevel>,
+}
+
+impl_pack_struct!(Identity { scope, permission });
+
+impl IdentityProof {
+    /// Signs a proof that the holder of `key` controls `signer`
+    #[must_use]
+    pub fn sign(key: &PrivateKey, chain_id: Checksum256, scope: Name, signer: PermissionLevel, expiration: TimePointSec) -> Self {
+        let digest = identity_transaction(scope, signer, expiration).signing_digest(&chain_id, &[]);
+        IdentityProof {
+            chain_id,
+            scope,
+            expiration,
+            signer,
+            signature: key.sign_digest(&digest),
+        }
+    }
+
+    /// Returns the transaction signed by the proof
+    #[must_use]
+    pub fn transaction(&self) -> Transaction {
+        identity_transaction(self.scope, self.signer, self.expiration)
+    }
+
+    /// Returns the digest signed by the proof, the signing digest of its transaction
+    #[must_use]
+    pub fn signing_digest(&self) -> Checksum256 {
+        self.transaction().signing_digest(&self.chain_id, &[])
+    }
+
+    /**
+     * Recovers the public key that signed the proof
+     *
+     * The key must then be checked against the permission of the account on chain.
+     */
+    pub fn recover_key(&self) -> Result<PublicKey, CryptoError> {
+        self.signature.recover_digest(&self.signing_digest())
+    }
+
+    /**
+     * Verifies that the proof was signed by `key` for `chain_id` and `scope`, and has not expired at `now`
+     *
+     * The proof is valid strictly before its expiration.
+     */
+    pub fn verify(&self, key: &PublicKey, chain_id: &Checksum256, scope: Name, now: TimePointSec) -> Result<(), ProofError> {
+        if self.chain_id != *chain_id {
+            return Err(ProofError::ChainMismatch(self.chain_id));
+        }
+        if self.scope != scope {
+            return Err(ProofError::ScopeMismatch(self.scope));
+        }
+        if now >= self.expiration {
+            return Err(ProofError::Expired(self.expiration));
+        }
+        if !self.signature.verify_digest(&self.signing_digest(), key) {
+            return Err(ProofError::InvalidSignature);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the `identity` transaction signed by wallets, which can't be included in a block
+fn identity_transaction(scope: Name, signer: PermissionLevel, expiration: TimePointSec) -> Transaction {
+    let identity = Identity {
+        scope,
+        permission: Some(signer),
+    };
+    let action = Action::new(Name::default(), Name::from("identity"), vec![signer], &identity);
+    let header = TransactionHeader {
+        expiration,
+        ..Default::default()
+    };
+    Transaction::new(header, vec![action])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{from_hex, to_hex};
+    use crate::{pack, unpack, KeyType};
+
+    const CHAIN_ID: &str = "aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906";
+    const WIF: &str = "5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3";
+
+    fn proof(key: &PrivateKey) -> IdentityProof {
+        IdentityProof::sign(
+            key,
+            Checksum256::from(CHAIN_ID),
+            Name::from("myapp"),
+            PermissionLevel::from("alice@active"),
+            TimePointSec::from_iso_string("2024-01-01T00:05:00"),
+        )
+    }
+
+    #[test]
+    fn test_message() {
+        let key = PrivateKey::from(WIF);
+        let chain_id = Checksum256::from(CHAIN_ID);
+        let sig = key.sign_message(&chain_id, b"hello");
+        assert!(sig.verify_message(&chain_id, b"hello", &key.public_key()));
+        assert!(!sig.verify_message(&chain_id, b"world", &key.public_key()));
+        assert!(!sig.verify_message(&Checksum256::default(), b"hello", &key.public_key()));
+        assert_eq!(sig.recover_message(&chain_id, b"hello"), Ok(key.public_key()));
+
+        // a message is never signed like a transaction, whose digest starts with the chain id
+        assert_ne!(
+            message_digest(&chain_id, b"hello"),
+            sha256(&[&chain_id.data, b"hello".as_slice()].concat())
+        );
+        assert!(!sig.verify(&[&chain_id.data, b"hello".as_slice()].concat(), &key.public_key()));
+    }
+
+    #[test]
+    fn test_transaction() {
+        // a header with only the expiration, no context free actions, then `identity` on account ""
+        // authorized by the signer, with `identity_v3` data and no extensions
+        let expected = [
+            "ac019265000000000000000000",
+            "0001",
+            "0000000000000000",
+            "0000003ebb3c5572",
+            "010000000000855c3400000000a8ed3232",
+            "19",
+            "00000000805a8d97",
+            "010000000000855c3400000000a8ed3232",
+            "00",
+        ]
+        .concat();
+        let proof = proof(&PrivateKey::from(WIF));
+        assert_eq!(to_hex(&pack(&proof.transaction())), expected);
+        assert_eq!(
+            proof.signing_digest(),
+            sha256(&[from_hex(CHAIN_ID).unwrap(), from_hex(&expected).unwrap(), vec![0; 32]].concat())
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let chain_id = Checksum256::from(CHAIN_ID);
+        let scope = Name::from("myapp");
+        let now = TimePointSec::from_iso_string("2024-01-01T00:00:00");
+        for key in [
+            PrivateKey::from(WIF),
+            PrivateKey::from("PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx"),
+        ] {
+            let proof = proof(&key);
+            assert_eq!(proof.verify(&key.public_key(), &chain_id, scope, now), Ok(()));
+            assert_eq!(proof.recover_key(), Ok(key.public_key()));
+        }
+
+        let key = PrivateKey::from(WIF);
+        let proof = proof(&key);
+        assert_eq!(
+            proof.verify(&key.public_key(), &chain_id, scope, proof.expiration),
+            Err(ProofError::Expired(proof.expiration))
+        );
+        assert_eq!(
+            proof.verify(&key.public_key(), &Checksum256::default(), scope, now),
+            Err(ProofError::ChainMismatch(chain_id))
+        );
+        assert_eq!(
+            proof.verify(&key.public_key(), &chain_id, Name::from("otherapp"), now),
+            Err(ProofError::ScopeMismatch(scope))
+        );
+        let other = PrivateKey::from_bytes(KeyType::K1, &[1; 32]).unwrap();
+        assert_eq!(
+            proof.verify(&other.public_key(), &chain_id, scope, now),
+            Err(ProofError::InvalidSignature)
+        );
+
+        // the signed fields can't be changed
+        let mut tampered = proof.clone();
+        tampered.signer = PermissionLevel::from("alice@owner");
+        assert_eq!(
+            tampered.verify(&key.public_key(), &chain_id, scope, now),
+            Err(ProofError::InvalidSignature)
+        );
+        let mut tampered = proof.clone();
+        tampered.expiration += 60;
+        assert_eq!(
+            tampered.verify(&key.public_key(), &chain_id, scope, now),
+            Err(ProofError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_pack() {
+        let proof = proof(&PrivateKey::from(WIF));
+        let packed = pack(&proof);
+        assert_eq!(packed.len(), 32 + 8 + 4 + 16 + 1 + 65);
+        assert_eq!(unpack::<IdentityProof>(&packed), Ok(proof));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let proof = proof(&PrivateKey::from(WIF));
+        let json = serde_json::to_value(&proof).unwrap();
+        assert_eq!(json["chain_id"], CHAIN_ID);
+        assert_eq!(json["signer"], serde_json::json!({"actor": "alice", "permission": "active"}));
+        assert_eq!(json["expiration"], "2024-01-01T00:05:00");
+        assert_eq!(serde_json::from_value::<IdentityProof>(json).unwrap(), proof);
+    }
+}
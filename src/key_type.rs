@@ -0,0 +1,116 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ripemd160, ParseError, UnpackError};
+
+/// The `KeyType` enum represents the curve of a key or signature
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/public_key.hpp>
+///
+/// The discriminant is the variant index used in the binary format.
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+pub enum KeyType {
+    /// secp256k1
+    #[default]
+    K1 = 0,
+    /// secp256r1 (NIST P-256)
+    R1 = 1,
+    /// WebAuthn, a secp256r1 key bound to a relying party
+    WA = 2,
+}
+
+impl KeyType {
+    /// Returns the variant index of the key type
+    #[inline]
+    #[must_use]
+    pub fn index(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the key type for a variant index
+    pub fn from_index(index: u32) -> Result<Self, UnpackError> {
+        match index {
+            0 => Ok(KeyType::K1),
+            1 => Ok(KeyType::R1),
+            2 => Ok(KeyType::WA),
+            _ => Err(UnpackError::BadData(format!("unknown key type {}", index))),
+        }
+    }
+
+    /// Returns the suffix used in the string formats, e.g. "K1"
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyType::K1 => "K1",
+            KeyType::R1 => "R1",
+            KeyType::WA => "WA",
+        }
+    }
+}
+
+impl FromStr for KeyType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "K1" => Ok(KeyType::K1),
+            "R1" => Ok(KeyType::R1),
+            "WA" => Ok(KeyType::WA),
+            _ => Err(ParseError::BadFormat),
+        }
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns the first four bytes of `ripemd160(data || suffix)`
+fn key_checksum(data: &[u8], suffix: &str) -> [u8; 4] {
+    let digest = ripemd160(&[data, suffix.as_bytes()].concat());
+    [digest.data[0], digest.data[1], digest.data[2], digest.data[3]]
+}
+
+/// Encodes `data` as base58 with a ripemd160 checksum, salted with `suffix` (empty for the legacy format)
+pub(crate) fn encode_key(data: &[u8], suffix: &str) -> String {
+    let mut bytes = data.to_vec();
+    bytes.extend_from_slice(&key_checksum(data, suffix));
+    bs58::encode(bytes).into_string()
+}
+
+/// Decodes base58 `s` and verifies its ripemd160 checksum, returning the data without the checksum
+pub(crate) fn decode_key(s: &str, suffix: &str) -> Option<Vec<u8>> {
+    let mut bytes = bs58::decode(s).into_vec().ok()?;
+    if bytes.len() < 4 {
+        return None;
+    }
+    let checksum = bytes.split_off(bytes.len() - 4);
+    (checksum == key_checksum(&bytes, suffix)).then_some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_type() {
+        assert_eq!(KeyType::from_str("R1"), Ok(KeyType::R1));
+        assert_eq!(KeyType::from_str("k1"), Err(ParseError::BadFormat));
+        assert_eq!(KeyType::WA.to_string(), "WA");
+        assert_eq!(KeyType::from_index(KeyType::WA.index()), Ok(KeyType::WA));
+        assert_eq!(KeyType::from_index(3), Err(UnpackError::BadData("unknown key type 3".to_string())));
+    }
+
+    #[test]
+    fn test_checksum() {
+        let data = [1_u8, 2, 3];
+        assert_eq!(decode_key(&encode_key(&data, "K1"), "K1"), Some(data.to_vec()));
+        assert_eq!(decode_key(&encode_key(&data, "K1"), "R1"), None);
+        assert_eq!(decode_key(&encode_key(&data, ""), ""), Some(data.to_vec()));
+        assert_eq!(decode_key("0OIl", ""), None);
+        assert_eq!(decode_key("1", ""), None);
+    }
+}
@@ -15,11 +15,39 @@
 //!     - [x] [`extended_asset`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/asset.hpp)
 //! - [x] [`name`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/name.hpp)
 //! - [x] [`check`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/check.hpp)
-//! - [ ] [`time`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
+//! - [x] [`datastream`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/datastream.hpp) (`Pack` / `Unpack`)
+//! - [x] [`time`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
 //!     - [x] [`microseconds`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
 //!     - [x] [`time_point`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
 //!     - [x] [`time_point_sec`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
-//!     - [ ] [`block_timestamp`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
+//!     - [x] [`block_timestamp`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/time.hpp)
+//! - [x] [`varint`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/varint.hpp)
+//! - [x] [`crypto`](https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/crypto.hpp)
+//!     - [x] `checksum160` / `checksum256` / `checksum512`
+//!     - [x] `public_key`
+//!     - [x] `signature`
+//!     - [x] `private_key` (`crypto` feature)
+//!     - [x] `bls_public_key` / `bls_signature` / `bls_private_key` (signing with `crypto` feature)
+//! - [x] [`action`](https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/action.hpp)
+//!     - [x] `permission_level`
+//! - [x] [`transaction`](https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp)
+//!     - [x] `transaction_header` / `signed_transaction`
+//!     - [x] `packed_transaction` (`none` and `zlib` compression)
+//! - [x] [`authority`](https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/authority.hpp) (with `validate`)
+//! - [x] [`keosd`](https://github.com/AntelopeIO/spring/tree/main/programs/keosd) `.wallet` files (`crypto` feature)
+
+#![cfg_attr(
+    test,
+    allow(
+        clippy::bool_assert_comparison,
+        clippy::bool_comparison,
+        clippy::clone_on_copy,
+        clippy::useless_conversion
+    )
+)]
+
+// lets the derive macros refer to `::antelope` from within this crate
+extern crate self as antelope;
 
 /// Modules for Asserts type.
 pub mod check;
@@ -29,6 +57,17 @@ pub use self::check::*;
 pub mod errors;
 pub use self::errors::*;
 
+#[cfg(feature = "serde")]
+mod serde_helpers;
+
+/// Modules for binary serialization.
+pub mod serializer;
+pub use self::serializer::*;
+
+/// Derive macros for `Pack`, `Unpack` and `UnpackBorrowed`.
+#[cfg(feature = "derive")]
+pub use antelope_derive::{Pack, Unpack, UnpackBorrowed};
+
 /// Modules for Symbol Code type.
 pub mod symbol_code;
 pub use self::symbol_code::*;
@@ -61,6 +100,125 @@ pub use self::microseconds::*;
 pub mod time_point;
 pub use self::time_point::*;
 
-/// Modules for TimePoint type.
+/// Modules for TimePointSec type.
 pub mod time_point_sec;
 pub use self::time_point_sec::*;
+
+/// Modules for BlockTimestamp type.
+pub mod block_timestamp;
+pub use self::block_timestamp::*;
+
+/// Modules for VarUint32 and VarInt32 types.
+pub mod varint;
+pub use self::varint::*;
+
+/// Modules for Float128 type.
+pub mod float128;
+pub use self::float128::*;
+
+/// Modules for Bytes type.
+pub mod bytes;
+pub use self::bytes::*;
+
+/// Modules for VecView type.
+pub mod vec_view;
+pub use self::vec_view::*;
+
+/// Modules for Checksum types.
+pub mod checksum;
+pub use self::checksum::*;
+
+/// Modules for KeyType type.
+pub mod key_type;
+pub use self::key_type::*;
+
+/// Modules for PublicKey type.
+pub mod public_key;
+pub use self::public_key::*;
+
+/// Modules for Signature type.
+pub mod signature;
+pub use self::signature::*;
+
+/// Modules for BLS types.
+pub mod bls;
+pub use self::bls::*;
+
+/// Modules for PermissionLevel type.
+pub mod permission_level;
+pub use self::permission_level::*;
+
+/// Modules for Action type.
+pub mod action;
+pub use self::action::*;
+
+/// Modules for Transaction types.
+pub mod transaction;
+pub use self::transaction::*;
+
+/// Modules for PackedTransaction type.
+pub mod packed_transaction;
+pub use self::packed_transaction::*;
+
+/// Modules for Authority type.
+pub mod authority;
+pub use self::authority::*;
+
+#[cfg(feature = "crypto")]
+mod aes_cbc;
+
+#[cfg(feature = "crypto")]
+mod ecc;
+
+#[cfg(feature = "crypto")]
+mod webauthn;
+
+/// Modules for PrivateKey type.
+#[cfg(feature = "crypto")]
+pub mod private_key;
+#[cfg(feature = "crypto")]
+pub use self::private_key::*;
+
+/// Modules for Wallet type.
+#[cfg(feature = "crypto")]
+pub mod wallet;
+#[cfg(feature = "crypto")]
+pub use self::wallet::*;
+
+/// Modules for EncryptedMessage type.
+#[cfg(feature = "crypto")]
+pub mod encrypted_message;
+#[cfg(feature = "crypto")]
+pub use self::encrypted_message::*;
+
+/// Modules for Mnemonic type.
+#[cfg(feature = "crypto")]
+pub mod mnemonic;
+#[cfg(feature = "crypto")]
+pub use self::mnemonic::*;
+
+/// Modules for IdentityProof type.
+#[cfg(feature = "crypto")]
+pub mod identity_proof;
+#[cfg(feature = "crypto")]
+pub use self::identity_proof::*;
+
+/// Random number generator traits accepted by `PrivateKey::generate`.
+#[cfg(feature = "crypto")]
+pub use rand_core;
+
+/// Modules for ABI definitions.
+#[cfg(feature = "abi")]
+pub mod abi;
+
+/// Modules for ABI type descriptions.
+#[cfg(feature = "abi")]
+pub mod abi_type;
+
+/// Derive macro for `AbiType`.
+#[cfg(all(feature = "derive", feature = "abi"))]
+pub use antelope_derive::AbiType;
+
+/// Modules for ABI serializer.
+#[cfg(feature = "abi")]
+pub mod abi_serializer;
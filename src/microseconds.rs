@@ -3,7 +3,7 @@ use core::str;
 use std::cmp::{Ord, Ordering, PartialEq, PartialOrd};
 use std::convert::From;
 
-use crate::check;
+use crate::{check, Pack, Unpack, UnpackError};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
 pub struct Microseconds {
@@ -86,6 +86,34 @@ pub fn days(d: i64) -> Microseconds {
     hours(24 * d)
 }
 
+impl Pack for Microseconds {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.count.pack(out)
+    }
+}
+
+impl Unpack for Microseconds {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(Microseconds::from(i64::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Microseconds {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.count)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Microseconds {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serde_helpers::deserialize_number::<i64, D>(deserializer).map(Microseconds::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,4 +205,20 @@ mod tests {
         assert_eq!(minutes_micro.count(), 60000000);
         assert_eq!(seconds_micro.count(), 1000000);
     }
+
+    #[test]
+    fn test_pack() {
+        let micro = Microseconds::from(1_000_000);
+        assert_eq!(micro.packed(), [0x40, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(crate::unpack::<Microseconds>(&micro.packed()), Ok(micro));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = Microseconds::from(-1_500_000);
+        assert_eq!(serde_json::to_string(&value).unwrap(), "-1500000");
+        assert_eq!(serde_json::from_str::<Microseconds>("-1500000").unwrap(), value);
+        assert_eq!(serde_json::from_str::<Microseconds>(r#""-1500000""#).unwrap(), value);
+    }
 }
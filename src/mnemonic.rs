@@ -0,0 +1,215 @@
+use std::fmt;
+use std::str::FromStr;
+
+use bip32::{DerivationPath, XPrv};
+use rand_core::CryptoRngCore;
+
+use crate::{KeyType, ParseError, PrivateKey};
+
+/// The SLIP-44 coin type of EOS, used by Antelope wallets
+pub const ANTELOPE_COIN_TYPE: u32 = 194;
+
+/// The BIP44 path of the external chain of the first account, keys are its children
+pub const ANTELOPE_DERIVATION_PATH: &str = "m/44'/194'/0'/0";
+
+/// The `Mnemonic` struct represents a BIP39 seed phrase
+///
+/// Reference: <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>
+///
+/// Only the English wordlist is supported. Parsing checks the words, the word count (12, 15, 18,
+/// 21 or 24) and the checksum. K1 keys are derived with BIP32 along `m/44'/194'/0'/0/n`.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::Mnemonic;
+///
+/// let mnemonic = Mnemonic::from("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+/// let key = mnemonic.derive_key("", 0).unwrap();
+/// assert_eq!("EOS6zpSNY1YoLxNt2VsvJjoDfBueU6xC1M1ERJw1UoekL1NHn8KNA", key.public_key().to_string());
+///
+/// assert!("abandon abandon abandon".parse::<Mnemonic>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    inner: bip39::Mnemonic,
+}
+
+impl Mnemonic {
+    /**
+     * Generates a new mnemonic of `word_count` words using the caller's random number generator
+     *
+     * `word_count` must be 12, 15, 18, 21 or 24.
+     */
+    pub fn generate(word_count: usize, rng: &mut impl CryptoRngCore) -> Result<Self, ParseError> {
+        if !(12..=24).contains(&word_count) || !word_count.is_multiple_of(3) {
+            return Err(ParseError::BadMnemonic(format!("invalid word count: {}", word_count)));
+        }
+        // every 3 words encode 32 bits of entropy and 1 bit of checksum
+        let mut entropy = [0u8; 32];
+        let entropy = &mut entropy[..word_count / 3 * 4];
+        rng.fill_bytes(entropy);
+        Mnemonic::from_entropy(entropy)
+    }
+
+    /// Creates the mnemonic encoding `entropy`, which must be 16 to 32 bytes in steps of 4
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, ParseError> {
+        bip39::Mnemonic::from_entropy(entropy)
+            .map(|inner| Mnemonic { inner })
+            .map_err(|e| ParseError::BadMnemonic(e.to_string()))
+    }
+
+    /// Returns the space separated words
+    #[must_use]
+    pub fn phrase(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// Returns the number of words
+    #[inline]
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        self.inner.word_count()
+    }
+
+    /// Returns the entropy encoded by the words
+    #[must_use]
+    pub fn to_entropy(&self) -> Vec<u8> {
+        self.inner.to_entropy()
+    }
+
+    /// Returns the 64 byte BIP39 seed, salted with the optional `passphrase`
+    #[must_use]
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        self.inner.to_seed(passphrase)
+    }
+
+    /// Derives the K1 key at `m/44'/194'/0'/0/index`
+    pub fn derive_key(&self, passphrase: &str, index: u32) -> Result<PrivateKey, ParseError> {
+        self.derive_path(passphrase, &format!("{}/{}", ANTELOPE_DERIVATION_PATH, index))
+    }
+
+    /// Derives the K1 key at a BIP32 `path`, such as `m/44'/194'/0'/0/0`
+    pub fn derive_path(&self, passphrase: &str, path: &str) -> Result<PrivateKey, ParseError> {
+        let err = || ParseError::BadDerivationPath(path.to_string());
+        let parsed = DerivationPath::from_str(path).map_err(|_| err())?;
+        let xprv = XPrv::derive_from_path(self.to_seed(passphrase), &parsed).map_err(|_| err())?;
+        PrivateKey::from_bytes(KeyType::K1, &xprv.to_bytes()).map_err(|_| err())
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = ParseError;
+
+    /**
+     * Parse Mnemonic from English words, validating the word count and checksum
+     *
+     * Words are separated by whitespace and compared in lowercase. Errors give the
+     * position of a bad word, not the word itself.
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join(" ");
+        bip39::Mnemonic::parse_in_normalized(bip39::Language::English, &normalized)
+            .map(|inner| Mnemonic { inner })
+            .map_err(|e| ParseError::BadMnemonic(e.to_string()))
+    }
+}
+
+impl From<&str> for Mnemonic {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse mnemonic: {}", e))
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    /**
+     * Prints the word count, keeping the phrase out of logs
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mnemonic").field("word_count", &self.word_count()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::to_hex;
+    use rand_core::OsRng;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_seed() {
+        // BIP39 reference vectors
+        let mnemonic = Mnemonic::from(PHRASE);
+        assert_eq!(mnemonic.word_count(), 12);
+        assert_eq!(mnemonic.to_entropy(), [0; 16]);
+        assert_eq!(
+            to_hex(&mnemonic.to_seed("TREZOR")),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+        assert_eq!(
+            Mnemonic::from_entropy(&[0x7f; 16]).unwrap().phrase(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+        assert_eq!(
+            Mnemonic::from(" Abandon  abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ABOUT\n"),
+            mnemonic
+        );
+    }
+
+    #[test]
+    fn test_derive() {
+        // keys computed with an independent BIP32 implementation
+        let mnemonic = Mnemonic::from(PHRASE);
+        let cases = [
+            ("", 0, "9f6cd81ace4dab5124f589852aec0933b6d44cf612d03040d084ccae9391ac80"),
+            ("", 1, "d3f134323997819a9e55369224841f8cd4d597cc284eb9440cc2c213bcc13384"),
+            ("TREZOR", 0, "b86dc904cc68a221008ec93537900de687db6249b1c780ace2d13fabab399b91"),
+            ("TREZOR", 1, "bf4a135735ce93158643e5888d9573ece40a311ba2dac5adac786059ffb6ff84"),
+        ];
+        for (passphrase, index, expected) in cases {
+            let key = mnemonic.derive_key(passphrase, index).unwrap();
+            assert_eq!(key.key_type(), KeyType::K1);
+            assert_eq!(to_hex(key.as_bytes()), expected);
+        }
+        assert_eq!(mnemonic.derive_path("", "m/44'/194'/0'/0/1"), mnemonic.derive_key("", 1));
+        assert_ne!(mnemonic.derive_path("", "m/44'/194'/1'/0/0"), mnemonic.derive_key("", 0));
+
+        for path in ["", "44'/194'", "m/44'/x", "m/2147483648"] {
+            assert_eq!(mnemonic.derive_path("", path), Err(ParseError::BadDerivationPath(path.to_string())));
+        }
+        assert!(mnemonic.derive_key("", 1 << 31).is_err());
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for phrase in [
+            "",
+            "abandon abandon abandon",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon antelope",
+        ] {
+            assert!(matches!(Mnemonic::from_str(phrase), Err(ParseError::BadMnemonic(_))));
+        }
+        let err = Mnemonic::from_str("secret abandon abandon").unwrap_err().to_string();
+        assert!(!err.contains("secret"));
+        assert!(!format!("{:?}", Mnemonic::from(PHRASE)).contains("abandon"));
+    }
+
+    #[test]
+    fn test_generate() {
+        for word_count in [12, 15, 18, 21, 24] {
+            let mnemonic = Mnemonic::generate(word_count, &mut OsRng).unwrap();
+            assert_eq!(mnemonic.word_count(), word_count);
+            assert_eq!(Mnemonic::from_str(&mnemonic.phrase()), Ok(mnemonic.clone()));
+            assert_ne!(Mnemonic::generate(word_count, &mut OsRng).unwrap(), mnemonic);
+        }
+        for word_count in [0, 11, 13, 27] {
+            assert!(matches!(
+                Mnemonic::generate(word_count, &mut OsRng),
+                Err(ParseError::BadMnemonic(_))
+            ));
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::convert::From;
 use std::fmt;
 use std::str::FromStr;
 
-use crate::{check, ParseError};
+use crate::{check, Pack, ParseError, Unpack, UnpackError};
 
 pub const NAME_CHARS: [u8; 32] = *b".12345abcdefghijklmnopqrstuvwxyz";
 
@@ -29,7 +29,7 @@ pub const NAME_MAX_LEN: usize = 13;
 /// assert_eq!(10920248689889378304, account.value);
 /// assert_eq!("myaccount", account.to_string());
 /// ```
-#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default)]
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
 pub struct Name {
     /// The raw value of the name
     ///
@@ -308,6 +308,23 @@ impl From<Name> for bool {
     }
 }
 
+impl Pack for Name {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.value.pack(out)
+    }
+}
+
+impl Unpack for Name {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(Name::from(u64::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(Name);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -438,13 +455,13 @@ mod tests {
     fn test_cdt_6() {
         // constexpr explicit operator bool()const
         // Note that I must be explicit about calling the operator because it is defined as `explicit`
-        assert_eq!(false, Name::from(0).into());
-        assert_eq!(true, Name::from(1).into());
+        assert_eq!(false, bool::from(Name::from(0)));
+        assert_eq!(true, bool::from(Name::from(1)));
 
-        assert_eq!(false, Name::from("").into());
-        assert_eq!(true, Name::from("1").into());
+        assert_eq!(false, bool::from(Name::from("")));
+        assert_eq!(true, bool::from(Name::from("1")));
 
-        assert_eq!(true, false == Name::from(0).into());
+        assert_eq!(true, false == bool::from(Name::from(0)));
     }
 
     #[test]
@@ -654,4 +671,24 @@ mod tests {
             prop_assert_eq!(Name::from_str(input.as_str()), Err(ParseError::BadName(input.to_string())));
         }
     }
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(Name::from("eosio").packed(), [0x00, 0x00, 0x00, 0x00, 0x00, 0xea, 0x30, 0x55]);
+        assert_eq!(Name::from("eosio.token").packed(), [0x00, 0xa6, 0x82, 0x34, 0x03, 0xea, 0x30, 0x55]);
+        assert_eq!(
+            crate::unpack::<Name>(&Name::from("eosio.token").packed()),
+            Ok(Name::from("eosio.token"))
+        );
+        assert_eq!(crate::unpack::<Name>(&[0xea, 0x30, 0x55]), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = Name::from("eosio.token");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""eosio.token""#);
+        assert_eq!(serde_json::from_str::<Name>(r#""eosio.token""#).unwrap(), value);
+        assert!(serde_json::from_str::<Name>(r#""Invalid""#).is_err());
+    }
 }
@@ -0,0 +1,256 @@
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use crate::serializer::impl_pack_struct;
+use crate::{pack, sha256, unpack, Bytes, Checksum256, Pack, Signature, SignedTransaction, Transaction, Unpack, UnpackError};
+
+/// The maximum size of decompressed data, like `nodeos` limits it
+pub const MAX_DECOMPRESSED_SIZE: usize = 1024 * 1024;
+
+/// The `CompressionType` enum represents how the content of a [`PackedTransaction`] is compressed
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp>
+#[derive(Eq, Copy, Clone, Debug, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum CompressionType {
+    /// No compression
+    #[default]
+    None = 0,
+    /// zlib compression
+    Zlib = 1,
+}
+
+impl Pack for CompressionType {
+    fn pack(&self, out: &mut Vec<u8>) {
+        (*self as u8).pack(out)
+    }
+}
+
+impl Unpack for CompressionType {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        match u8::unpack(data)? {
+            0 => Ok(CompressionType::None),
+            1 => Ok(CompressionType::Zlib),
+            n => Err(UnpackError::BadData(format!("unknown compression type: {}", n))),
+        }
+    }
+}
+
+/// The `PackedTransaction` struct represents a signed transaction in the form pushed to `nodeos`
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp>
+///
+/// The transaction and its context free data are packed, then compressed with `compression`.
+/// Its JSON is the body of the `push_transaction` and `send_transaction` endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{CompressionType, PackedTransaction, SignedTransaction, Transaction};
+///
+/// let signed = SignedTransaction::new(Transaction::default());
+/// let packed = PackedTransaction::new(&signed, CompressionType::Zlib);
+/// assert_eq!(signed.id(), packed.id().unwrap());
+/// assert_eq!(signed, packed.to_signed().unwrap());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedTransaction {
+    /// The signatures of the transaction
+    pub signatures: Vec<Signature>,
+    /// The compression of `packed_context_free_data` and `packed_trx`
+    pub compression: CompressionType,
+    /// The packed context free data, empty when there is none
+    pub packed_context_free_data: Bytes,
+    /// The packed transaction
+    pub packed_trx: Bytes,
+}
+
+impl PackedTransaction {
+    /// Packs and compresses a signed transaction
+    #[must_use]
+    pub fn new(signed: &SignedTransaction, compression: CompressionType) -> Self {
+        let packed_context_free_data = match signed.context_free_data.is_empty() {
+            true => vec![],
+            false => compress(&pack(&signed.context_free_data), compression),
+        };
+        PackedTransaction {
+            signatures: signed.signatures.clone(),
+            compression,
+            packed_context_free_data: Bytes::from(packed_context_free_data),
+            packed_trx: Bytes::from(compress(&pack(&signed.transaction), compression)),
+        }
+    }
+
+    /// Decompresses and unpacks the transaction
+    pub fn transaction(&self) -> Result<Transaction, UnpackError> {
+        unpack(&decompress(self.packed_trx.as_slice(), self.compression)?)
+    }
+
+    /// Decompresses and unpacks the context free data
+    pub fn context_free_data(&self) -> Result<Vec<Bytes>, UnpackError> {
+        if self.packed_context_free_data.is_empty() {
+            return Ok(vec![]);
+        }
+        unpack(&decompress(self.packed_context_free_data.as_slice(), self.compression)?)
+    }
+
+    /// Returns the id of the transaction, the sha256 of the decompressed `packed_trx`
+    pub fn id(&self) -> Result<Checksum256, UnpackError> {
+        Ok(sha256(&decompress(self.packed_trx.as_slice(), self.compression)?))
+    }
+
+    /// Decompresses and unpacks the signed transaction
+    pub fn to_signed(&self) -> Result<SignedTransaction, UnpackError> {
+        Ok(SignedTransaction {
+            transaction: self.transaction()?,
+            signatures: self.signatures.clone(),
+            context_free_data: self.context_free_data()?,
+        })
+    }
+}
+
+impl_pack_struct!(PackedTransaction {
+    signatures,
+    compression,
+    packed_context_free_data,
+    packed_trx
+});
+
+impl From<&SignedTransaction> for PackedTransaction {
+    fn from(signed: &SignedTransaction) -> Self {
+        PackedTransaction::new(signed, CompressionType::None)
+    }
+}
+
+impl TryFrom<&PackedTransaction> for SignedTransaction {
+    type Error = UnpackError;
+
+    fn try_from(packed: &PackedTransaction) -> Result<Self, Self::Error> {
+        packed.to_signed()
+    }
+}
+
+fn compress(data: &[u8], compression: CompressionType) -> Vec<u8> {
+    match compression {
+        CompressionType::None => data.to_vec(),
+        CompressionType::Zlib => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(data).expect("writing to a Vec never fails");
+            encoder.finish().expect("writing to a Vec never fails")
+        }
+    }
+}
+
+fn decompress(data: &[u8], compression: CompressionType) -> Result<Vec<u8>, UnpackError> {
+    match compression {
+        CompressionType::None => Ok(data.to_vec()),
+        CompressionType::Zlib => {
+            let mut out = Vec::new();
+            ZlibDecoder::new(data)
+                .take(MAX_DECOMPRESSED_SIZE as u64 + 1)
+                .read_to_end(&mut out)
+                .map_err(|e| UnpackError::BadData(format!("zlib: {}", e)))?;
+            if out.len() > MAX_DECOMPRESSED_SIZE {
+                return Err(UnpackError::BadData("zlib: decompressed data too large".to_string()));
+            }
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Action, Name, PermissionLevel, TimePointSec, TransactionHeader};
+
+    fn signed() -> SignedTransaction {
+        let header = TransactionHeader {
+            expiration: TimePointSec::from_iso_string("2018-06-15T19:17:47"),
+            ref_block_num: 1234,
+            ref_block_prefix: 0x01020304,
+            ..Default::default()
+        };
+        let action = Action::new(
+            Name::from("eosio"),
+            Name::from("refund"),
+            vec![PermissionLevel::from("alice@active")],
+            &Name::from("alice"),
+        );
+        let mut signed = SignedTransaction::new(Transaction::new(header, vec![action; 10]));
+        signed.signatures.push(Signature::from(
+            "SIG_K1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx",
+        ));
+        signed
+    }
+
+    #[test]
+    fn test_compression() {
+        let mut signed = signed();
+        for compression in [CompressionType::None, CompressionType::Zlib] {
+            let packed = PackedTransaction::new(&signed, compression);
+            assert!(packed.packed_context_free_data.is_empty());
+            assert_eq!(packed.id(), Ok(signed.id()));
+            assert_eq!(packed.to_signed(), Ok(signed.clone()));
+            assert_eq!(unpack::<PackedTransaction>(&pack(&packed)), Ok(packed));
+        }
+
+        let none = PackedTransaction::from(&signed);
+        assert_eq!(none.packed_trx.as_slice(), pack(&signed.transaction).as_slice());
+        let zlib = PackedTransaction::new(&signed, CompressionType::Zlib);
+        assert_eq!(zlib.packed_trx.as_slice()[0], 0x78);
+        assert!(zlib.packed_trx.len() < none.packed_trx.len());
+
+        signed.context_free_data = vec![Bytes::from(vec![1, 2]), Bytes::from(vec![])];
+        let zlib = PackedTransaction::new(&signed, CompressionType::Zlib);
+        assert_eq!(zlib.context_free_data(), Ok(signed.context_free_data.clone()));
+        assert_eq!(SignedTransaction::try_from(&zlib), Ok(signed.clone()));
+        let none = PackedTransaction::from(&signed);
+        assert_eq!(none.packed_context_free_data.as_slice(), [2, 2, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            unpack::<CompressionType>(&[2]),
+            Err(UnpackError::BadData("unknown compression type: 2".to_string()))
+        );
+
+        let mut packed = PackedTransaction::new(&signed(), CompressionType::Zlib);
+        packed.packed_trx = Bytes::from(vec![1, 2, 3]);
+        assert!(matches!(packed.transaction(), Err(UnpackError::BadData(_))));
+
+        // decompression stops at the size limit
+        let bomb = compress(&vec![0; MAX_DECOMPRESSED_SIZE + 1], CompressionType::Zlib);
+        assert!(bomb.len() < 2048);
+        assert_eq!(
+            decompress(&bomb, CompressionType::Zlib),
+            Err(UnpackError::BadData("zlib: decompressed data too large".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let packed = PackedTransaction::new(&signed(), CompressionType::Zlib);
+        let json = serde_json::to_value(&packed).unwrap();
+        assert_eq!(json["compression"], "zlib");
+        assert_eq!(json["packed_context_free_data"], "");
+        assert_eq!(json["packed_trx"], crate::bytes::to_hex(packed.packed_trx.as_slice()));
+        assert_eq!(
+            json["signatures"][0],
+            "SIG_K1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx"
+        );
+        assert_eq!(serde_json::from_value::<PackedTransaction>(json).unwrap(), packed);
+
+        let json =
+            r#"{"signatures":[],"compression":"none","packed_context_free_data":"","packed_trx":"00000000000000000000000000000000"}"#;
+        let packed = serde_json::from_str::<PackedTransaction>(json).unwrap();
+        assert_eq!(packed.transaction(), Ok(Transaction::default()));
+        assert_eq!(serde_json::to_string(&packed).unwrap(), json);
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::serializer::impl_pack_struct;
+use crate::{Name, ParseError};
+
+/// The `PermissionLevel` struct represents an account permission authorizing an action
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/action.hpp>
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Name, PermissionLevel};
+///
+/// let auth = PermissionLevel::from("alice@active");
+/// assert_eq!(Name::from("alice"), auth.actor);
+/// assert_eq!(Name::from("active"), auth.permission);
+/// assert_eq!("alice@active", auth.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PermissionLevel {
+    /// The account
+    pub actor: Name,
+    /// The permission of the account
+    pub permission: Name,
+}
+
+impl PermissionLevel {
+    /// Creates a permission level from an account and one of its permissions
+    #[inline]
+    #[must_use]
+    pub fn new(actor: Name, permission: Name) -> Self {
+        PermissionLevel { actor, permission }
+    }
+}
+
+impl_pack_struct!(PermissionLevel { actor, permission });
+
+impl FromStr for PermissionLevel {
+    type Err = ParseError;
+
+    /**
+     * Parse PermissionLevel from "actor@permission" strings, where neither name is empty
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::BadPermissionLevel(s.to_string());
+        let (actor, permission) = s.split_once('@').ok_or_else(err)?;
+        if actor.is_empty() || permission.is_empty() {
+            return Err(err());
+        }
+        Ok(PermissionLevel {
+            actor: Name::from_str(actor).map_err(|_| err())?,
+            permission: Name::from_str(permission).map_err(|_| err())?,
+        })
+    }
+}
+
+impl From<&str> for PermissionLevel {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse permission level: {}", e))
+    }
+}
+
+impl fmt::Display for PermissionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{}", self.actor, self.permission)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{unpack, Pack};
+
+    #[test]
+    fn test_from_str() {
+        let auth = PermissionLevel::from_str("eosio.token@active").unwrap();
+        assert_eq!(auth, PermissionLevel::new(Name::from("eosio.token"), Name::from("active")));
+        assert_eq!(auth.to_string(), "eosio.token@active");
+
+        for s in ["", "alice", "alice@", "@active", "alice@active@owner", "ALICE@active"] {
+            assert_eq!(PermissionLevel::from_str(s), Err(ParseError::BadPermissionLevel(s.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_pack() {
+        let auth = PermissionLevel::from("alice@active");
+        let packed = auth.packed();
+        assert_eq!(packed.len(), 16);
+        assert_eq!(&packed[..8], Name::from("alice").packed().as_slice());
+        assert_eq!(unpack::<PermissionLevel>(&packed), Ok(auth));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let auth = PermissionLevel::from("alice@active");
+        let json = r#"{"actor":"alice","permission":"active"}"#;
+        assert_eq!(serde_json::to_string(&auth).unwrap(), json);
+        assert_eq!(serde_json::from_str::<PermissionLevel>(json).unwrap(), auth);
+    }
+}
@@ -0,0 +1,408 @@
+use std::fmt;
+use std::str::FromStr;
+
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use rand_core::CryptoRngCore;
+
+use crate::key_type::{decode_key, encode_key};
+use crate::{
+    pack_varuint32, read_bytes, sha256, sha512, unpack_varuint32, Checksum256, Checksum512, CryptoError, KeyType, Pack, ParseError,
+    PublicKey, Signature, Unpack, UnpackError, SIGNATURE_RECOVERY_OFFSET,
+};
+
+/// The version byte prefixed to legacy WIF private keys
+pub const PRIVATE_KEY_WIF_VERSION: u8 = 0x80;
+
+/// The size of a K1 or R1 private key
+pub const PRIVATE_KEY_SIZE: usize = 32;
+
+/// The `PrivateKey` struct represents a K1 or R1 private key
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/private_key.hpp>
+///
+/// The secret scalar can only be set through `from_bytes`, `generate` or parsing, which check
+/// that it is valid for the curve.
+/// WebAuthn keys live in authenticators, so there are no `WA` private keys.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{PrivateKey, PublicKey};
+///
+/// let key = PrivateKey::from("5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3");
+/// assert_eq!("PVT_K1_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V", key.to_pvt_string());
+/// assert_eq!(PublicKey::from("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV"), key.public_key());
+/// ```
+#[derive(Eq, Clone, PartialEq, Hash)]
+pub struct PrivateKey {
+    key_type: KeyType,
+    data: [u8; PRIVATE_KEY_SIZE],
+}
+
+impl PrivateKey {
+    /// Creates a private key from its type and secret scalar, validating it for the curve
+    pub fn from_bytes(key_type: KeyType, data: &[u8]) -> Result<Self, UnpackError> {
+        let invalid = || UnpackError::BadData(format!("invalid {} private key", key_type));
+        if data.len() != PRIVATE_KEY_SIZE {
+            return Err(invalid());
+        }
+        let valid = match key_type {
+            KeyType::K1 => k256::SecretKey::from_slice(data).is_ok(),
+            KeyType::R1 => p256::SecretKey::from_slice(data).is_ok(),
+            KeyType::WA => false,
+        };
+        if !valid {
+            return Err(invalid());
+        }
+        let mut key = PrivateKey {
+            key_type,
+            data: [0; PRIVATE_KEY_SIZE],
+        };
+        key.data.copy_from_slice(data);
+        Ok(key)
+    }
+
+    /**
+     * Generates a new private key of `key_type` using the caller's random number generator
+     *
+     * Panics for `KeyType::WA`, which has no private keys.
+     */
+    pub fn generate(key_type: KeyType, rng: &mut impl CryptoRngCore) -> Self {
+        let data = match key_type {
+            KeyType::K1 => k256::SecretKey::random(rng).to_bytes(),
+            KeyType::R1 => p256::SecretKey::random(rng).to_bytes(),
+            KeyType::WA => panic!("WA private keys cannot be generated"),
+        };
+        PrivateKey {
+            key_type,
+            data: data.into(),
+        }
+    }
+
+    /// Returns the curve of the key
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    /// Returns the secret scalar, big-endian
+    pub fn as_bytes(&self) -> &[u8; PRIVATE_KEY_SIZE] {
+        &self.data
+    }
+
+    /// Returns the public key matching this private key
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        let point = match self.key_type {
+            KeyType::K1 => k256::SecretKey::from_bytes(&self.data.into())
+                .expect("validated private key")
+                .public_key()
+                .to_encoded_point(true),
+            _ => p256::SecretKey::from_bytes(&self.data.into())
+                .expect("validated private key")
+                .public_key()
+                .to_encoded_point(true),
+        };
+        PublicKey {
+            key_type: self.key_type,
+            data: point.as_bytes().to_vec(),
+        }
+    }
+
+    /**
+     * Signs a sha256 `digest`
+     *
+     * K1 signatures are retried with a fresh nonce until they are canonical, as `nodeos` requires.
+     */
+    #[must_use]
+    pub fn sign_digest(&self, digest: &Checksum256) -> Signature {
+        let mut attempt: u32 = 0;
+        loop {
+            let ad = match attempt {
+                0 => vec![],
+                n => n.to_le_bytes().to_vec(),
+            };
+            let (rs, recid) = match self.key_type {
+                KeyType::K1 => crate::ecc::k1::sign(&self.data, &digest.data, &ad),
+                _ => crate::ecc::r1::sign(&self.data, &digest.data, &ad),
+            };
+            let mut data = vec![SIGNATURE_RECOVERY_OFFSET + recid];
+            data.extend_from_slice(&rs);
+            let sig = Signature {
+                key_type: self.key_type,
+                data,
+            };
+            if sig.is_canonical() {
+                return sig;
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Signs `data`, hashed with sha256
+    #[must_use]
+    pub fn sign(&self, data: &[u8]) -> Signature {
+        self.sign_digest(&sha256(data))
+    }
+
+    /**
+     * Signs an arbitrary message on `chain_id`
+     *
+     * The digest is domain separated from transactions, see [`crate::message_digest`].
+     */
+    #[must_use]
+    pub fn sign_message(&self, chain_id: &Checksum256, data: &[u8]) -> Signature {
+        self.sign_digest(&crate::message_digest(chain_id, data))
+    }
+
+    /**
+     * Derives the secret shared with the owner of `public_key`, like `fc::ecc::private_key::get_shared_secret`
+     *
+     * Both keys must be on the same curve. The secret is the sha512 of the x coordinate of the
+     * ECDH point, so either party computes it from its private key and the other's public key.
+     */
+    pub fn shared_secret(&self, public_key: &PublicKey) -> Result<Checksum512, CryptoError> {
+        if public_key.key_type != self.key_type {
+            return Err(CryptoError::UnsupportedKeyType(public_key.key_type));
+        }
+        let x = match self.key_type {
+            KeyType::K1 => crate::ecc::k1::shared_secret(&self.data, &public_key.data),
+            _ => crate::ecc::r1::shared_secret(&self.data, &public_key.data),
+        };
+        Ok(sha512(&x.ok_or(CryptoError::InvalidPublicKey)?))
+    }
+
+    /// Returns the key in the "PVT_K1_..." or "PVT_R1_..." format
+    #[must_use]
+    pub fn to_pvt_string(&self) -> String {
+        format!("PVT_{}_{}", self.key_type, encode_key(&self.data, self.key_type.as_str()))
+    }
+
+    /// Returns the key in the legacy WIF format, which only exists for K1 keys
+    #[must_use]
+    pub fn to_wif(&self) -> Option<String> {
+        if self.key_type != KeyType::K1 {
+            return None;
+        }
+        let mut bytes = vec![PRIVATE_KEY_WIF_VERSION];
+        bytes.extend_from_slice(&self.data);
+        let checksum = sha256(sha256(&bytes).as_bytes());
+        bytes.extend_from_slice(&checksum.as_bytes()[..4]);
+        Some(bs58::encode(bytes).into_string())
+    }
+
+    /// Decodes a legacy WIF string, verifying its double sha256 checksum
+    fn from_wif(s: &str) -> Option<Vec<u8>> {
+        let mut bytes = bs58::decode(s).into_vec().ok()?;
+        if bytes.len() != PRIVATE_KEY_SIZE + 5 || bytes[0] != PRIVATE_KEY_WIF_VERSION {
+            return None;
+        }
+        let checksum = bytes.split_off(PRIVATE_KEY_SIZE + 1);
+        (checksum == sha256(sha256(&bytes).as_bytes()).as_bytes()[..4]).then(|| bytes.split_off(1))
+    }
+}
+
+impl FromStr for PrivateKey {
+    type Err = ParseError;
+
+    /**
+     * Parse PrivateKey from "PVT_K1_...", "PVT_R1_..." or legacy WIF strings
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::BadPrivateKey(s.to_string());
+        let (key_type, data) = if let Some(rest) = s.strip_prefix("PVT_") {
+            let (key_type, encoded) = rest.split_once('_').ok_or_else(err)?;
+            let key_type = KeyType::from_str(key_type).map_err(|_| err())?;
+            (key_type, decode_key(encoded, key_type.as_str()).ok_or_else(err)?)
+        } else {
+            (KeyType::K1, PrivateKey::from_wif(s).ok_or_else(err)?)
+        };
+        PrivateKey::from_bytes(key_type, &data).map_err(|_| err())
+    }
+}
+
+impl From<&str> for PrivateKey {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse private key: {}", e))
+    }
+}
+
+impl fmt::Display for PrivateKey {
+    /**
+     * Converts the PrivateKey into string
+     *
+     * K1 keys use the legacy WIF format, like `cleos` does.
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_wif() {
+            Some(wif) => f.write_str(&wif),
+            None => f.write_str(&self.to_pvt_string()),
+        }
+    }
+}
+
+impl fmt::Debug for PrivateKey {
+    /**
+     * Prints the key type and public key, keeping the secret out of logs
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("key_type", &self.key_type)
+            .field("public_key", &self.public_key().to_string())
+            .finish()
+    }
+}
+
+impl Pack for PrivateKey {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.key_type.index(), out);
+        out.extend_from_slice(&self.data);
+    }
+}
+
+impl Unpack for PrivateKey {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let key_type = KeyType::from_index(unpack_varuint32(data)?)?;
+        PrivateKey::from_bytes(key_type, read_bytes(data, PRIVATE_KEY_SIZE)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(PrivateKey);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::to_hex;
+    use rand_core::OsRng;
+
+    const WIF: &str = "5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3";
+    const K1: &str = "PVT_K1_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V";
+    const K1_HEX: &str = "d2653ff7cbb2d8ff129ac27ef5781ce68b2558c41a74af1f2ddca635cbeef07d";
+    const R1: &str = "PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx";
+
+    #[test]
+    fn test_from_str() {
+        let key = PrivateKey::from_str(WIF).unwrap();
+        assert_eq!(key.key_type, KeyType::K1);
+        assert_eq!(to_hex(&key.data), K1_HEX);
+        assert_eq!(PrivateKey::from_str(K1), Ok(key.clone()));
+        assert_eq!(key.to_string(), WIF);
+        assert_eq!(key.to_wif().unwrap(), WIF);
+        assert_eq!(key.to_pvt_string(), K1);
+
+        let key = PrivateKey::from_str(R1).unwrap();
+        assert_eq!(key.key_type, KeyType::R1);
+        assert_eq!(
+            to_hex(&key.data),
+            "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+        );
+        assert_eq!(key.to_string(), R1);
+        assert_eq!(key.to_wif(), None);
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for s in [
+            "",
+            "PVT_K1_",
+            "PVT_WA_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V",
+            "PVT_R1_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V",
+            "5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD4",
+            "EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV",
+            // zero is not a valid scalar
+            "PVT_K1_111111111111111111111111111111112omJse",
+            // the order of the R1 curve is not a valid scalar
+            "PVT_R1_2wkBESjd7JjPhhQyfe9kCoij1ARUxanfqUbWrXtgCa4t8NcYXY",
+        ] {
+            assert_eq!(PrivateKey::from_str(s), Err(ParseError::BadPrivateKey(s.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_public_key() {
+        assert_eq!(
+            PrivateKey::from(WIF).public_key().to_string(),
+            "EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV"
+        );
+        assert_eq!(
+            PrivateKey::from(R1).public_key().to_string(),
+            "PUB_R1_5WKbPCbDSvpmYEKzxnZXLVpcVVxWHZDWDNdhUapQwTUDsb5GBV"
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        for key_type in [KeyType::K1, KeyType::R1] {
+            let key = PrivateKey::generate(key_type, &mut OsRng);
+            assert_eq!(key.key_type, key_type);
+            assert_eq!(PrivateKey::from_str(&key.to_string()), Ok(key.clone()));
+            assert_eq!(key.public_key().key_type, key_type);
+            assert_ne!(PrivateKey::generate(key_type, &mut OsRng), key);
+        }
+    }
+
+    #[test]
+    fn test_sign() {
+        for key in [
+            PrivateKey::from(WIF),
+            PrivateKey::from(R1),
+            PrivateKey::generate(KeyType::K1, &mut OsRng),
+        ] {
+            let digest = sha256(b"hello");
+            let sig = key.sign_digest(&digest);
+            assert_eq!(sig.key_type, key.key_type);
+            assert_eq!(key.sign(b"hello"), sig);
+            assert!(sig.verify_digest(&digest, &key.public_key()));
+            assert!(!sig.verify(b"world", &key.public_key()));
+        }
+
+        // K1 signing retries until both r and s are canonical
+        let key = PrivateKey::from(WIF);
+        for i in 0..64_u32 {
+            assert!(key.sign(&i.to_le_bytes()).is_canonical());
+        }
+    }
+
+    #[test]
+    fn test_shared_secret() {
+        let alice = PrivateKey::from(WIF);
+        let bob = PrivateKey::from_bytes(KeyType::K1, &(1..=32).collect::<Vec<u8>>()).unwrap();
+        let secret = alice.shared_secret(&bob.public_key()).unwrap();
+        assert_eq!(bob.shared_secret(&alice.public_key()), Ok(secret));
+        assert_eq!(
+            to_hex(&secret.data),
+            "ef4f1e9f3e9b391867139678a59dfbbaa16cde77f10395ee26062490f8811f98060c52618eca87667d93269b91894c1a943ca30fb7b853ad3895c8b17b7a06a9"
+        );
+
+        let r1 = PrivateKey::from(R1);
+        let other = PrivateKey::generate(KeyType::R1, &mut OsRng);
+        assert_eq!(r1.shared_secret(&other.public_key()), other.shared_secret(&r1.public_key()));
+        assert_eq!(
+            r1.shared_secret(&alice.public_key()),
+            Err(CryptoError::UnsupportedKeyType(KeyType::K1))
+        );
+        let mut bad = alice.public_key();
+        bad.data[1..].fill(0xff);
+        assert_eq!(alice.shared_secret(&bad), Err(CryptoError::InvalidPublicKey));
+    }
+
+    #[test]
+    fn test_pack() {
+        let key = PrivateKey::from(WIF);
+        assert_eq!(to_hex(&key.packed()), format!("00{}", K1_HEX));
+        assert_eq!(crate::unpack::<PrivateKey>(&key.packed()), Ok(key.clone()));
+        assert_eq!(
+            crate::unpack::<PrivateKey>(&[0; 33]),
+            Err(UnpackError::BadData("invalid K1 private key".to_string()))
+        );
+        assert!(!format!("{:?}", key).contains(WIF));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let key = PrivateKey::from(K1);
+        assert_eq!(serde_json::to_string(&key).unwrap(), format!(r#""{}""#, WIF));
+        assert_eq!(serde_json::from_str::<PrivateKey>(&format!(r#""{}""#, K1)).unwrap(), key);
+    }
+}
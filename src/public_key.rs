@@ -0,0 +1,290 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::key_type::{decode_key, encode_key};
+use crate::{pack_varuint32, read_bytes, unpack_varuint32, KeyType, Pack, ParseError, Unpack, UnpackError};
+
+/// The prefix of legacy K1 public keys
+pub const PUBLIC_KEY_LEGACY_PREFIX: &str = "EOS";
+
+/// The size of a compressed K1 or R1 public key
+pub const PUBLIC_KEY_SIZE: usize = 33;
+
+/// The `UserPresence` enum represents the authenticator flags bound to a WebAuthn public key
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/webauthn.hpp>
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+pub enum UserPresence {
+    /// The user was not asked to interact with the authenticator
+    #[default]
+    None = 0,
+    /// The user touched the authenticator
+    Present = 1,
+    /// The user was verified by the authenticator, e.g. with a PIN or biometrics
+    Verified = 2,
+}
+
+impl UserPresence {
+    /// Returns the user presence for its packed value
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(UserPresence::None),
+            1 => Some(UserPresence::Present),
+            2 => Some(UserPresence::Verified),
+            _ => None,
+        }
+    }
+}
+
+/// The `PublicKey` struct represents a K1, R1 or WebAuthn public key
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/public_key.hpp>
+///
+/// `data` holds the compressed key, followed for WebAuthn keys by the user presence
+/// byte and the relying party id, exactly as they are packed.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{KeyType, PublicKey};
+///
+/// let key = PublicKey::from("PUB_K1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq63");
+/// assert_eq!(KeyType::K1, key.key_type);
+/// assert_eq!("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV", key.to_string());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, PartialOrd, Ord, Hash)]
+pub struct PublicKey {
+    /// The curve of the key
+    pub key_type: KeyType,
+    /// The packed key data
+    pub data: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Creates a public key from its type and packed data, validating the data length
+    pub fn from_bytes(key_type: KeyType, data: &[u8]) -> Result<Self, UnpackError> {
+        let mut input = data;
+        let key = Self::unpack_data(key_type, &mut input)?;
+        if !input.is_empty() {
+            return Err(UnpackError::TrailingBytes(input.len()));
+        }
+        Ok(key)
+    }
+
+    /// Creates a WebAuthn public key from a compressed R1 point, the required user presence and the relying party id
+    pub fn webauthn(point: &[u8], user_presence: UserPresence, rpid: &str) -> Result<Self, UnpackError> {
+        let mut data = point.to_vec();
+        data.push(user_presence as u8);
+        rpid.pack(&mut data);
+        PublicKey::from_bytes(KeyType::WA, &data)
+    }
+
+    /// Returns the user presence required by a WebAuthn key
+    #[must_use]
+    pub fn user_presence(&self) -> Option<UserPresence> {
+        match self.key_type {
+            KeyType::WA => UserPresence::from_u8(self.data[PUBLIC_KEY_SIZE]),
+            _ => None,
+        }
+    }
+
+    /// Returns the relying party id of a WebAuthn key, e.g. "example.com"
+    #[must_use]
+    pub fn rpid(&self) -> Option<String> {
+        match self.key_type {
+            KeyType::WA => String::unpack(&mut &self.data[PUBLIC_KEY_SIZE + 1..]).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the key in the "PUB_K1_...", "PUB_R1_..." or "PUB_WA_..." format
+    #[must_use]
+    pub fn to_pub_string(&self) -> String {
+        format!("PUB_{}_{}", self.key_type, encode_key(&self.data, self.key_type.as_str()))
+    }
+
+    /// Returns the key in the legacy "EOS..." format, which only exists for K1 keys
+    #[must_use]
+    pub fn to_legacy_string(&self) -> Option<String> {
+        match self.key_type {
+            KeyType::K1 => Some(format!("{}{}", PUBLIC_KEY_LEGACY_PREFIX, encode_key(&self.data, ""))),
+            _ => None,
+        }
+    }
+
+    /// Returns the compressed curve point, without the WebAuthn user presence and relying party id
+    #[inline]
+    #[must_use]
+    pub fn point(&self) -> &[u8] {
+        &self.data[..PUBLIC_KEY_SIZE]
+    }
+
+    /// Reads the key data for `key_type` from the front of `data`
+    fn unpack_data(key_type: KeyType, data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let start = *data;
+        read_bytes(data, PUBLIC_KEY_SIZE)?;
+        if key_type == KeyType::WA {
+            u8::unpack(data)?;
+            String::unpack(data)?;
+        }
+        let data = start[..start.len() - data.len()].to_vec();
+        Ok(PublicKey { key_type, data })
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = ParseError;
+
+    /**
+     * Parse PublicKey from "PUB_K1_...", "PUB_R1_...", "PUB_WA_..." or legacy "EOS..." strings
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::BadPublicKey(s.to_string());
+        let (key_type, data) = if let Some(rest) = s.strip_prefix("PUB_") {
+            let (key_type, encoded) = rest.split_once('_').ok_or_else(err)?;
+            let key_type = KeyType::from_str(key_type).map_err(|_| err())?;
+            (key_type, decode_key(encoded, key_type.as_str()).ok_or_else(err)?)
+        } else if let Some(encoded) = s.strip_prefix(PUBLIC_KEY_LEGACY_PREFIX) {
+            (KeyType::K1, decode_key(encoded, "").ok_or_else(err)?)
+        } else {
+            return Err(err());
+        };
+        let key = PublicKey::from_bytes(key_type, &data).map_err(|_| err())?;
+        // a compressed point starts with the parity of its y coordinate
+        match key.data[0] {
+            0x02 | 0x03 => Ok(key),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl From<&str> for PublicKey {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse public key: {}", e))
+    }
+}
+
+impl fmt::Display for PublicKey {
+    /**
+     * Converts the PublicKey into string
+     *
+     * K1 keys use the legacy "EOS..." format, like `nodeos` does.
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_legacy_string() {
+            Some(legacy) => f.write_str(&legacy),
+            None => f.write_str(&self.to_pub_string()),
+        }
+    }
+}
+
+impl Pack for PublicKey {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.key_type.index(), out);
+        out.extend_from_slice(&self.data);
+    }
+}
+
+impl Unpack for PublicKey {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let key_type = KeyType::from_index(unpack_varuint32(data)?)?;
+        PublicKey::unpack_data(key_type, data)
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(PublicKey);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::to_hex;
+
+    const LEGACY: &str = "EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV";
+    const K1: &str = "PUB_K1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq63";
+    const K1_HEX: &str = "02c0ded2bc1f1305fb0faac5e6c03ee3a1924234985427b6167ca569d13df435cf";
+    const R1: &str = "PUB_R1_6FPFZqw5ahYrR9jD96yDbbDNTdKtNqRbze6oTDLntrsANgQKZu";
+
+    #[test]
+    fn test_from_str() {
+        let key = PublicKey::from_str(K1).unwrap();
+        assert_eq!(key.key_type, KeyType::K1);
+        assert_eq!(to_hex(&key.data), K1_HEX);
+        assert_eq!(PublicKey::from_str(LEGACY), Ok(key.clone()));
+        assert_eq!(key.to_string(), LEGACY);
+        assert_eq!(key.to_pub_string(), K1);
+        assert_eq!(key.to_legacy_string().unwrap(), LEGACY);
+        assert_eq!(key.point(), key.data.as_slice());
+
+        let key = PublicKey::from_str(R1).unwrap();
+        assert_eq!(key.key_type, KeyType::R1);
+        assert_eq!(
+            to_hex(&key.data),
+            "02b323ea27d191143eb9ad27c96db15d8b129d3096a0cb17ae11ae26abce803340"
+        );
+        assert_eq!(key.to_string(), R1);
+        assert_eq!(key.to_pub_string(), R1);
+        assert_eq!(key.to_legacy_string(), None);
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for s in [
+            "",
+            "EOS",
+            "PUB_K1_",
+            "PUB_X1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq63",
+            "PUB_R1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq63",
+            "PUB_K1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq64",
+            "EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CW",
+            "EOS5MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV",
+            // valid checksum, but 0x04 is not a compressed point prefix
+            "PUB_K1_AEvKddUXzG5PLenMmunQ2WPSj4JummCNQKvNbH4Y9FE4UWRU8u",
+        ] {
+            assert_eq!(PublicKey::from_str(s), Err(ParseError::BadPublicKey(s.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_webauthn() {
+        let mut data = PublicKey::from(R1).data;
+        data.push(1);
+        "example.com".pack(&mut data);
+        let key = PublicKey::from_bytes(KeyType::WA, &data).unwrap();
+        assert_eq!(
+            PublicKey::webauthn(&data[..PUBLIC_KEY_SIZE], UserPresence::Present, "example.com"),
+            Ok(key.clone())
+        );
+        assert_eq!(key.user_presence(), Some(UserPresence::Present));
+        assert_eq!(key.rpid().unwrap(), "example.com");
+        assert_eq!(PublicKey::from(R1).rpid(), None);
+        assert!(key.to_string().starts_with("PUB_WA_"));
+        assert_eq!(key.point(), PublicKey::from(R1).data.as_slice());
+        assert_eq!(PublicKey::from_str(&key.to_string()), Ok(key.clone()));
+        assert_eq!(crate::unpack::<PublicKey>(&key.packed()), Ok(key));
+        assert_eq!(
+            PublicKey::from_bytes(KeyType::WA, &data[..PUBLIC_KEY_SIZE]),
+            Err(UnpackError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_pack() {
+        let key = PublicKey::from(K1);
+        assert_eq!(to_hex(&key.packed()), format!("00{}", K1_HEX));
+        assert_eq!(crate::unpack::<PublicKey>(&key.packed()), Ok(key));
+        assert_eq!(
+            crate::unpack::<PublicKey>(&[3; 34]),
+            Err(UnpackError::BadData("unknown key type 3".to_string()))
+        );
+        assert_eq!(crate::unpack::<PublicKey>(&[0; 33]), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let key = PublicKey::from(K1);
+        assert_eq!(serde_json::to_string(&key).unwrap(), format!(r#""{}""#, LEGACY));
+        assert_eq!(serde_json::from_str::<PublicKey>(&format!(r#""{}""#, K1)).unwrap(), key);
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers shared by the `serde` implementations, following `abi_serializer` JSON conventions.
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserializer, Serializer};
+
+/// Serializes a value as its `Display` string
+pub(crate) fn serialize_display<T: Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Deserializes a value from a string using its `FromStr` implementation
+pub(crate) fn deserialize_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    struct StrVisitor<T>(PhantomData<T>);
+
+    impl<T> Visitor<'_> for StrVisitor<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<T, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(StrVisitor(PhantomData))
+}
+
+/// Deserializes an integer that may be written either as a JSON number or as a string
+///
+/// `nodeos` writes 64-bit integers that don't fit in 32 bits as strings.
+pub(crate) fn deserialize_number<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr + TryFrom<i64> + TryFrom<u64>,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    struct NumberVisitor<T>(PhantomData<T>);
+
+    impl<T> Visitor<'_> for NumberVisitor<T>
+    where
+        T: FromStr + TryFrom<i64> + TryFrom<u64>,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("an integer or a string containing an integer")
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<T, E> {
+            T::try_from(v).map_err(|_| E::custom(format!("integer out of range: {}", v)))
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<T, E> {
+            T::try_from(v).map_err(|_| E::custom(format!("integer out of range: {}", v)))
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<T, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_any(NumberVisitor(PhantomData))
+}
+
+/// Implements `Serialize` / `Deserialize` through `Display` / `FromStr`
+macro_rules! impl_serde_string {
+    ($t:ty) => {
+        impl serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde_helpers::serialize_display(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serde_helpers::deserialize_from_str(deserializer)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_serde_string;
+
+/// Serializes a byte vector as a lowercase hex string, for use with `#[serde(with = "...")]`
+#[cfg(feature = "abi")]
+pub(crate) mod hex {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&crate::bytes::to_hex(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let hex = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        crate::bytes::from_hex(&hex).ok_or_else(|| D::Error::custom(format!("bad hex string: {}", hex)))
+    }
+}
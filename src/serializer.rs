@@ -0,0 +1,689 @@
+use std::collections::BTreeMap;
+
+use crate::UnpackError;
+
+/// The `Pack` trait serializes a value into the Antelope binary format
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/datastream.hpp>
+///
+/// Integers are written little-endian, lengths are prefixed as `varuint32` and
+/// structs are written field by field in declaration order, the same layout `nodeos` uses.
+///
+/// Packing never fails, except for a derived `Pack` whose binary extension is `Some` after one
+/// that is `None`, which panics.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Name, Pack};
+///
+/// let bytes = Name::from("eosio").packed();
+/// assert_eq!(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0xea, 0x30, 0x55], bytes);
+/// ```
+pub trait Pack {
+    /// Appends the binary representation of `self` to `out`
+    fn pack(&self, out: &mut Vec<u8>);
+
+    /// Returns the binary representation of `self`
+    fn packed(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.pack(&mut out);
+        out
+    }
+}
+
+/// The `Unpack` trait deserializes a value from the Antelope binary format
+///
+/// `unpack` reads from the front of `data` and advances it past the consumed bytes.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Name, Unpack};
+///
+/// let mut data: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0x00, 0xea, 0x30, 0x55];
+/// assert_eq!(Name::from("eosio"), Name::unpack(&mut data).unwrap());
+/// assert!(data.is_empty());
+/// ```
+pub trait Unpack: Sized {
+    /// Reads a value from the front of `data`
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError>;
+}
+
+/// The `UnpackBorrowed` trait deserializes a value that may borrow from the input
+///
+/// Every `Unpack` type implements it. `&str` and `&[u8]` read strings and bytes in place, and
+/// [`VecView`](crate::VecView) reads a vector without collecting it, so nothing is allocated when
+/// decoding e.g. `transfer` action data into a struct of `Name`, `Asset` and `&str` fields.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::UnpackBorrowed;
+///
+/// let input = [0x05, b'h', b'e', b'l', b'l', b'o'];
+/// let mut data: &[u8] = &input;
+/// let memo = <&str>::unpack_borrowed(&mut data).unwrap();
+/// assert_eq!("hello", memo);
+/// assert_eq!(input[1..].as_ptr(), memo.as_ptr());
+/// ```
+pub trait UnpackBorrowed<'a>: Sized {
+    /// Reads a value from the front of `data`, borrowing from it where possible
+    fn unpack_borrowed(data: &mut &'a [u8]) -> Result<Self, UnpackError>;
+}
+
+impl<'a, T: Unpack> UnpackBorrowed<'a> for T {
+    #[inline]
+    fn unpack_borrowed(data: &mut &'a [u8]) -> Result<Self, UnpackError> {
+        T::unpack(data)
+    }
+}
+
+impl<'a> UnpackBorrowed<'a> for &'a [u8] {
+    #[inline]
+    fn unpack_borrowed(data: &mut &'a [u8]) -> Result<Self, UnpackError> {
+        let len = unpack_length(data)?;
+        read_bytes(data, len)
+    }
+}
+
+impl<'a> UnpackBorrowed<'a> for &'a str {
+    #[inline]
+    fn unpack_borrowed(data: &mut &'a [u8]) -> Result<Self, UnpackError> {
+        std::str::from_utf8(<&[u8]>::unpack_borrowed(data)?).map_err(|_| UnpackError::BadUtf8)
+    }
+}
+
+/// Packs a value into a new byte vector
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{pack, Asset};
+///
+/// assert_eq!("102700000000000004454f5300000000", hex(&pack(&Asset::from("1.0000 EOS"))));
+/// # fn hex(b: &[u8]) -> String { b.iter().map(|b| format!("{:02x}", b)).collect() }
+/// ```
+pub fn pack<T: Pack + ?Sized>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.pack(&mut out);
+    out
+}
+
+/// Unpacks a value from a byte slice, failing if any bytes are left over
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{unpack, Asset};
+///
+/// let bytes = [0x10, 0x27, 0, 0, 0, 0, 0, 0, 0x04, 0x45, 0x4f, 0x53, 0, 0, 0, 0];
+/// assert_eq!(Asset::from("1.0000 EOS"), unpack::<Asset>(&bytes).unwrap());
+/// ```
+pub fn unpack<T: Unpack>(data: &[u8]) -> Result<T, UnpackError> {
+    let mut data = data;
+    let value = T::unpack(&mut data)?;
+    if !data.is_empty() {
+        return Err(UnpackError::TrailingBytes(data.len()));
+    }
+    Ok(value)
+}
+
+/// Unpacks a value borrowing from a byte slice, failing if any bytes are left over
+///
+/// # Examples
+///
+/// ```
+/// use antelope::unpack_borrowed;
+///
+/// let bytes = [0x03, 0x0a, 0x0b, 0x0c];
+/// assert_eq!(&[0x0a, 0x0b, 0x0c], unpack_borrowed::<&[u8]>(&bytes).unwrap());
+/// ```
+pub fn unpack_borrowed<'a, T: UnpackBorrowed<'a>>(data: &'a [u8]) -> Result<T, UnpackError> {
+    let mut data = data;
+    let value = T::unpack_borrowed(&mut data)?;
+    if !data.is_empty() {
+        return Err(UnpackError::TrailingBytes(data.len()));
+    }
+    Ok(value)
+}
+
+/// Reads exactly `len` bytes from the front of `data`
+pub fn read_bytes<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8], UnpackError> {
+    if data.len() < len {
+        return Err(UnpackError::UnexpectedEof);
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Ok(head)
+}
+
+/// Writes a `varuint32` (LEB128) value
+pub fn pack_varuint32(value: u32, out: &mut Vec<u8>) {
+    let mut v = value;
+    loop {
+        let mut byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if v > 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if v == 0 {
+            break;
+        }
+    }
+}
+
+/// Reads a `varuint32` (LEB128) value
+pub fn unpack_varuint32(data: &mut &[u8]) -> Result<u32, UnpackError> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = read_bytes(data, 1)?[0];
+        value |= ((byte & 0x7f) as u64) << shift;
+        if value > u32::MAX as u64 {
+            return Err(UnpackError::BadVarint);
+        }
+        if byte & 0x80 == 0 {
+            return Ok(value as u32);
+        }
+        shift += 7;
+        if shift >= 35 {
+            return Err(UnpackError::BadVarint);
+        }
+    }
+}
+
+/// Packs a binary extension field, clearing `present` once an extension is absent
+///
+/// Present values are packed without the `Option` flag, absent ones are omitted entirely.
+///
+/// # Panics
+///
+/// If `value` is `Some` after an absent extension, which nodeos rejects as it could not be decoded.
+pub fn pack_binary_extension<T: Pack>(value: &Option<T>, present: &mut bool, out: &mut Vec<u8>) {
+    match value {
+        Some(value) => {
+            assert!(*present, "binary extension is present after an absent one");
+            value.pack(out);
+        }
+        None => *present = false,
+    }
+}
+
+/// Unpacks a binary extension field, which is absent if there is no data left
+pub fn unpack_binary_extension<'a, T: UnpackBorrowed<'a>>(data: &mut &'a [u8]) -> Result<Option<T>, UnpackError> {
+    match data.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(T::unpack_borrowed(data)?)),
+    }
+}
+
+/// Reads a `varuint32` length prefix
+fn unpack_length(data: &mut &[u8]) -> Result<usize, UnpackError> {
+    Ok(unpack_varuint32(data)? as usize)
+}
+
+macro_rules! impl_pack_number {
+    ($($t:ty),*) => {
+        $(
+            impl Pack for $t {
+                #[inline]
+                fn pack(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+
+            impl Unpack for $t {
+                #[inline]
+                fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+                    let bytes = read_bytes(data, std::mem::size_of::<$t>())?;
+                    Ok(<$t>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+impl_pack_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+impl Pack for bool {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+}
+
+impl Unpack for bool {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        match u8::unpack(data)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            b => Err(UnpackError::BadBool(b)),
+        }
+    }
+}
+
+impl Pack for str {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.len() as u32, out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Pack for String {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.as_str().pack(out)
+    }
+}
+
+impl Unpack for String {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let len = unpack_length(data)?;
+        let bytes = read_bytes(data, len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| UnpackError::BadUtf8)
+    }
+}
+
+impl<T: Pack> Pack for [T] {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.len() as u32, out);
+        for item in self {
+            item.pack(out);
+        }
+    }
+}
+
+impl<T: Pack> Pack for Vec<T> {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.as_slice().pack(out)
+    }
+}
+
+impl<T: Unpack> Unpack for Vec<T> {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let len = unpack_length(data)?;
+        // every element takes at least one byte, so a length beyond the input is malformed
+        let mut items = Vec::with_capacity(len.min(data.len()));
+        for _ in 0..len {
+            items.push(T::unpack(data)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T: Pack, const N: usize> Pack for [T; N] {
+    fn pack(&self, out: &mut Vec<u8>) {
+        for item in self {
+            item.pack(out);
+        }
+    }
+}
+
+impl<T: Unpack, const N: usize> Unpack for [T; N] {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let items = (0..N).map(|_| T::unpack(data)).collect::<Result<Vec<T>, _>>()?;
+        Ok(items.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+impl<T: Pack> Pack for Option<T> {
+    fn pack(&self, out: &mut Vec<u8>) {
+        match self {
+            Some(value) => {
+                true.pack(out);
+                value.pack(out);
+            }
+            None => false.pack(out),
+        }
+    }
+}
+
+impl<T: Unpack> Unpack for Option<T> {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        match bool::unpack(data)? {
+            true => Ok(Some(T::unpack(data)?)),
+            false => Ok(None),
+        }
+    }
+}
+
+impl<A: Pack, B: Pack> Pack for (A, B) {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.0.pack(out);
+        self.1.pack(out);
+    }
+}
+
+impl<A: Unpack, B: Unpack> Unpack for (A, B) {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok((A::unpack(data)?, B::unpack(data)?))
+    }
+}
+
+impl<K: Pack, V: Pack> Pack for BTreeMap<K, V> {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.len() as u32, out);
+        for (key, value) in self {
+            key.pack(out);
+            value.pack(out);
+        }
+    }
+}
+
+impl<K: Unpack + Ord, V: Unpack> Unpack for BTreeMap<K, V> {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let len = unpack_length(data)?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            let key = K::unpack(data)?;
+            let value = V::unpack(data)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl<T: Pack + ?Sized> Pack for &T {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        (**self).pack(out)
+    }
+}
+
+impl<T: Pack + ?Sized> Pack for Box<T> {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        (**self).pack(out)
+    }
+}
+
+impl<T: Unpack> Unpack for Box<T> {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(Box::new(T::unpack(data)?))
+    }
+}
+
+/// Implements `Pack` / `Unpack` for a struct by serializing its fields in order
+#[allow(unused_macros)]
+macro_rules! impl_pack_struct {
+    ($t:ident { $($field:ident),* $(,)? }) => {
+        impl crate::Pack for $t {
+            fn pack(&self, out: &mut Vec<u8>) {
+                $(crate::Pack::pack(&self.$field, out);)*
+            }
+        }
+
+        impl crate::Unpack for $t {
+            fn unpack(data: &mut &[u8]) -> Result<Self, crate::UnpackError> {
+                Ok($t {
+                    $($field: crate::Unpack::unpack(data)?,)*
+                })
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_pack_struct;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{from_hex, to_hex};
+    use crate::{Asset, Name};
+    use proptest::prelude::*;
+
+    /// `eosio.token::transfer` action data
+    #[derive(Debug, PartialEq)]
+    struct Transfer {
+        from: Name,
+        to: Name,
+        quantity: Asset,
+        memo: String,
+    }
+
+    impl Pack for Transfer {
+        fn pack(&self, out: &mut Vec<u8>) {
+            self.from.pack(out);
+            self.to.pack(out);
+            self.quantity.pack(out);
+            self.memo.pack(out);
+        }
+    }
+
+    impl Unpack for Transfer {
+        fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+            Ok(Transfer {
+                from: Name::unpack(data)?,
+                to: Name::unpack(data)?,
+                quantity: Asset::unpack(data)?,
+                memo: String::unpack(data)?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_transfer() {
+        let transfer = Transfer {
+            from: Name::from("eosio"),
+            to: Name::from("eosio.token"),
+            quantity: Asset::from("1.0000 EOS"),
+            memo: "hello".to_string(),
+        };
+        let expected = "0000000000ea305500a6823403ea3055102700000000000004454f53000000000568656c6c6f";
+        assert_eq!(to_hex(&pack(&transfer)), expected);
+        assert_eq!(unpack::<Transfer>(&from_hex(expected).unwrap()).unwrap(), transfer);
+    }
+
+    #[test]
+    fn test_transfer_empty_memo() {
+        let transfer = Transfer {
+            from: Name::from("alice"),
+            to: Name::from("bob"),
+            quantity: Asset::from("0.0001 EOS"),
+            memo: "".to_string(),
+        };
+        let expected = "0000000000855c340000000000000e3d010000000000000004454f530000000000";
+        assert_eq!(to_hex(&pack(&transfer)), expected);
+        assert_eq!(unpack::<Transfer>(&from_hex(expected).unwrap()).unwrap(), transfer);
+    }
+
+    #[test]
+    fn test_varuint32() {
+        let cases: [(u32, &str); 6] = [
+            (0, "00"),
+            (1, "01"),
+            (127, "7f"),
+            (128, "8001"),
+            (16384, "808001"),
+            (u32::MAX, "ffffffff0f"),
+        ];
+        for (value, expected) in cases {
+            let mut out = Vec::new();
+            pack_varuint32(value, &mut out);
+            assert_eq!(to_hex(&out), expected);
+            assert_eq!(unpack_varuint32(&mut from_hex(expected).unwrap().as_slice()), Ok(value));
+        }
+        assert_eq!(
+            unpack_varuint32(&mut &[0xff, 0xff, 0xff, 0xff, 0x1f][..]),
+            Err(UnpackError::BadVarint)
+        );
+        assert_eq!(unpack_varuint32(&mut &[0x80][..]), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(to_hex(&pack(&true)), "01");
+        assert_eq!(to_hex(&pack(&-1_i16)), "ffff");
+        assert_eq!(to_hex(&pack(&0x01020304_u32)), "04030201");
+        assert_eq!(to_hex(&pack(&1.5_f64)), "000000000000f83f");
+        assert_eq!(to_hex(&pack("abc")), "03616263");
+        assert_eq!(to_hex(&pack(&vec![1_u16, 2])), "0201000200");
+        assert_eq!(to_hex(&pack(&Some(7_u8))), "0107");
+        assert_eq!(to_hex(&pack(&None::<u8>)), "00");
+        assert_eq!(to_hex(&pack(&[1_u8, 2, 3])), "010203");
+        assert_eq!(to_hex(&pack(&BTreeMap::from([(2_u8, true), (1_u8, false)]))), "0201000201");
+    }
+
+    #[test]
+    fn test_unpack_errors() {
+        assert_eq!(unpack::<u32>(&[1, 2, 3]), Err(UnpackError::UnexpectedEof));
+        assert_eq!(unpack::<u8>(&[1, 2]), Err(UnpackError::TrailingBytes(1)));
+        assert_eq!(unpack::<bool>(&[2]), Err(UnpackError::BadBool(2)));
+        assert_eq!(unpack::<String>(&[2, 0xff, 0xfe]), Err(UnpackError::BadUtf8));
+        assert_eq!(unpack::<Vec<u8>>(&[0xff, 0xff, 0xff, 0xff, 0x0f]), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive() {
+        #[derive(Debug, PartialEq, crate::Pack, crate::Unpack)]
+        struct Derived {
+            from: Name,
+            to: Name,
+            quantity: Asset,
+            memo: String,
+        }
+
+        #[derive(Debug, PartialEq, crate::Pack, crate::Unpack)]
+        struct Tuple(
+            u8,
+            #[antelope(binary_extension)] Option<u16>,
+            #[antelope(binary_extension)] Option<u8>,
+        );
+
+        #[derive(Debug, PartialEq, crate::Pack, crate::Unpack)]
+        enum Variant {
+            Empty,
+            Value(u8),
+            Named { a: u8, b: bool },
+        }
+
+        let expected = "0000000000ea305500a6823403ea3055102700000000000004454f53000000000568656c6c6f";
+        let derived = unpack::<Derived>(&from_hex(expected).unwrap()).unwrap();
+        assert_eq!(derived.memo, "hello");
+        assert_eq!(to_hex(&pack(&derived)), expected);
+
+        assert_eq!(to_hex(&pack(&Tuple(1, Some(2), Some(3)))), "01020003");
+        assert_eq!(unpack::<Tuple>(&[1]), Ok(Tuple(1, None, None)));
+        assert_eq!(unpack::<Tuple>(&[1, 2, 0]), Ok(Tuple(1, Some(2), None)));
+        assert_eq!(unpack::<Tuple>(&[1, 2]), Err(UnpackError::UnexpectedEof));
+
+        assert_eq!(to_hex(&pack(&Variant::Empty)), "00");
+        assert_eq!(to_hex(&pack(&Variant::Value(7))), "0107");
+        assert_eq!(to_hex(&pack(&Variant::Named { a: 7, b: true })), "020701");
+        assert_eq!(unpack::<Variant>(&[2, 7, 1]), Ok(Variant::Named { a: 7, b: true }));
+        assert_eq!(
+            unpack::<Variant>(&[3]),
+            Err(UnpackError::BadData("unknown variant 3 of Variant".to_string()))
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    #[should_panic(expected = "binary extension is present after an absent one")]
+    fn test_derive_extension_after_absent() {
+        #[derive(crate::Pack)]
+        struct Tuple(
+            u8,
+            #[antelope(binary_extension)] Option<u16>,
+            #[antelope(binary_extension)] Option<u8>,
+        );
+
+        pack(&Tuple(1, None, Some(3)));
+    }
+
+    /// `eosio.token::transfer` action data, borrowing the memo
+    #[derive(Debug, PartialEq)]
+    struct TransferRef<'a> {
+        from: Name,
+        to: Name,
+        quantity: Asset,
+        memo: &'a str,
+    }
+
+    impl<'a> UnpackBorrowed<'a> for TransferRef<'a> {
+        fn unpack_borrowed(data: &mut &'a [u8]) -> Result<Self, UnpackError> {
+            Ok(TransferRef {
+                from: Name::unpack(data)?,
+                to: Name::unpack(data)?,
+                quantity: Asset::unpack(data)?,
+                memo: <&str>::unpack_borrowed(data)?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_unpack_borrowed() {
+        let bytes = from_hex("0000000000ea305500a6823403ea3055102700000000000004454f53000000000568656c6c6f").unwrap();
+        let transfer = unpack_borrowed::<TransferRef>(&bytes).unwrap();
+        assert_eq!(transfer.from, Name::from("eosio"));
+        assert_eq!(transfer.quantity, Asset::from("1.0000 EOS"));
+        assert_eq!(transfer.memo, "hello");
+        assert_eq!(transfer.memo.as_ptr(), bytes[33..].as_ptr());
+
+        assert_eq!(unpack_borrowed::<&[u8]>(&[0x02, 0x01, 0x02]), Ok(&[0x01_u8, 0x02][..]));
+        assert_eq!(unpack_borrowed::<&[u8]>(&[0x02, 0x01]), Err(UnpackError::UnexpectedEof));
+        assert_eq!(unpack_borrowed::<&str>(&[0x01, 0xff]), Err(UnpackError::BadUtf8));
+        assert_eq!(unpack_borrowed::<&str>(&[0x00, 0x00]), Err(UnpackError::TrailingBytes(1)));
+        assert_eq!(unpack_borrowed::<u16>(&[0x01, 0x02]), Ok(0x0201));
+        assert_eq!(unpack_binary_extension::<&str>(&mut &[0x01, b'a'][..]), Ok(Some("a")));
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn test_derive_borrowed() {
+        #[derive(Debug, PartialEq, crate::Pack, crate::UnpackBorrowed)]
+        struct Transfer<'a> {
+            from: Name,
+            to: Name,
+            quantity: Asset,
+            memo: &'a str,
+        }
+
+        #[derive(Debug, PartialEq, crate::Pack, crate::UnpackBorrowed)]
+        struct Batch<'a> {
+            transfers: crate::VecView<'a, Transfer<'a>>,
+            data: &'a [u8],
+            #[antelope(binary_extension)]
+            note: Option<&'a str>,
+        }
+
+        #[derive(Debug, PartialEq, crate::Pack, crate::UnpackBorrowed)]
+        enum Memo<'a> {
+            Text(&'a str),
+            Raw(&'a [u8]),
+        }
+
+        let expected = "0000000000ea305500a6823403ea3055102700000000000004454f53000000000568656c6c6f";
+        let bytes = from_hex(expected).unwrap();
+        let transfer = unpack_borrowed::<Transfer>(&bytes).unwrap();
+        assert_eq!(transfer.memo, "hello");
+        assert_eq!(to_hex(&pack(&transfer)), expected);
+
+        let bytes = from_hex(&format!("01{}02abcd", expected)).unwrap();
+        let batch = unpack_borrowed::<Batch>(&bytes).unwrap();
+        assert_eq!(batch.transfers.to_vec(), [transfer]);
+        assert_eq!(batch.data, [0xab, 0xcd]);
+        assert_eq!(batch.note, None);
+        assert_eq!(pack(&batch), bytes);
+
+        assert_eq!(unpack_borrowed::<Memo>(&[0x00, 0x01, b'a']), Ok(Memo::Text("a")));
+        assert_eq!(unpack_borrowed::<Memo>(&[0x01, 0x01, 0xff]), Ok(Memo::Raw(&[0xff])));
+        assert_eq!(
+            unpack_borrowed::<Memo>(&[0x02]),
+            Err(UnpackError::BadData("unknown variant 2 of Memo".to_string()))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_roundtrip(a in any::<u64>(), b in any::<i32>(), s in ".*", v in any::<Vec<u16>>(), o in any::<Option<i8>>()) {
+            let value = (a, (b, (s, (v, o))));
+            prop_assert_eq!(unpack::<(u64, (i32, (String, (Vec<u16>, Option<i8>))))>(&pack(&value)).unwrap(), value);
+        }
+    }
+}
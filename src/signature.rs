@@ -0,0 +1,356 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::key_type::{decode_key, encode_key};
+use crate::{pack_varuint32, read_bytes, unpack_varuint32, KeyType, Pack, ParseError, Unpack, UnpackError};
+#[cfg(feature = "crypto")]
+use crate::{Checksum256, CryptoError, PublicKey};
+
+/// The size of a compact K1 or R1 signature, including the recovery byte
+pub const SIGNATURE_SIZE: usize = 65;
+
+/// The offset added to the recovery id in the first byte of a compact signature, for compressed keys
+pub const SIGNATURE_RECOVERY_OFFSET: u8 = 27 + 4;
+
+/// The `Signature` struct represents a K1, R1 or WebAuthn signature
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/libfc/include/fc/crypto/signature.hpp>
+///
+/// `data` holds the compact signature, followed for WebAuthn signatures by the
+/// authenticator data and the client data JSON, exactly as they are packed.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{KeyType, Signature};
+///
+/// let sig = Signature::from("SIG_K1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx");
+/// assert_eq!(KeyType::K1, sig.key_type);
+/// assert_eq!(65, sig.data.len());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, PartialOrd, Ord, Hash)]
+pub struct Signature {
+    /// The curve of the signing key
+    pub key_type: KeyType,
+    /// The packed signature data
+    pub data: Vec<u8>,
+}
+
+impl Signature {
+    /// Creates a signature from its type and packed data, validating the data length
+    pub fn from_bytes(key_type: KeyType, data: &[u8]) -> Result<Self, UnpackError> {
+        let mut input = data;
+        let sig = Self::unpack_data(key_type, &mut input)?;
+        if !input.is_empty() {
+            return Err(UnpackError::TrailingBytes(input.len()));
+        }
+        Ok(sig)
+    }
+
+    /**
+     * Returns true if the signature is canonical
+     *
+     * `nodeos` rejects K1 signatures whose `r` or `s` have the high bit set or an unnecessary
+     * leading zero byte. R1 and WebAuthn signatures are not subject to this rule.
+     */
+    #[must_use]
+    pub fn is_canonical(&self) -> bool {
+        let c = &self.data;
+        self.key_type != KeyType::K1
+            || (c[1] & 0x80 == 0 && !(c[1] == 0 && c[2] & 0x80 == 0) && c[33] & 0x80 == 0 && !(c[33] == 0 && c[34] & 0x80 == 0))
+    }
+
+    /// Creates a WebAuthn signature from a compact R1 signature, the authenticator data and the client data JSON
+    pub fn webauthn(compact: &[u8], auth_data: &[u8], client_json: &str) -> Result<Self, UnpackError> {
+        let mut data = compact.to_vec();
+        auth_data.pack(&mut data);
+        client_json.pack(&mut data);
+        Signature::from_bytes(KeyType::WA, &data)
+    }
+
+    /// Returns the authenticator data of a WebAuthn signature
+    #[must_use]
+    pub fn auth_data(&self) -> Option<Vec<u8>> {
+        self.webauthn_parts().map(|(auth_data, _)| auth_data)
+    }
+
+    /// Returns the client data JSON of a WebAuthn signature
+    #[must_use]
+    pub fn client_json(&self) -> Option<String> {
+        self.webauthn_parts().map(|(_, client_json)| client_json)
+    }
+
+    fn webauthn_parts(&self) -> Option<(Vec<u8>, String)> {
+        if self.key_type != KeyType::WA {
+            return None;
+        }
+        let mut data = &self.data[SIGNATURE_SIZE..];
+        Some((Vec::<u8>::unpack(&mut data).ok()?, String::unpack(&mut data).ok()?))
+    }
+
+    /// Reads the signature data for `key_type` from the front of `data`
+    fn unpack_data(key_type: KeyType, data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let start = *data;
+        read_bytes(data, SIGNATURE_SIZE)?;
+        if key_type == KeyType::WA {
+            Vec::<u8>::unpack(data)?;
+            String::unpack(data)?;
+        }
+        let data = start[..start.len() - data.len()].to_vec();
+        Ok(Signature { key_type, data })
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl Signature {
+    /**
+     * Verifies the signature of a sha256 `digest` against `key`
+     *
+     * Non-canonical K1 signatures are rejected, like `nodeos` does. WebAuthn signatures
+     * must also match the relying party id and user presence of `key`.
+     */
+    #[must_use]
+    pub fn verify_digest(&self, digest: &Checksum256, key: &PublicKey) -> bool {
+        if self.key_type != key.key_type || !self.is_canonical() {
+            return false;
+        }
+        let rs = &self.data[1..SIGNATURE_SIZE];
+        match self.key_type {
+            KeyType::K1 => crate::ecc::k1::verify(&key.data, &digest.data, rs),
+            KeyType::R1 => crate::ecc::r1::verify(&key.data, &digest.data, rs),
+            KeyType::WA => self.recover_digest(digest).is_ok_and(|recovered| recovered == *key),
+        }
+    }
+
+    /// Verifies the signature of `data`, hashed with sha256, against `key`
+    #[must_use]
+    pub fn verify(&self, data: &[u8], key: &PublicKey) -> bool {
+        self.verify_digest(&crate::sha256(data), key)
+    }
+
+    /**
+     * Recovers the public key that signed a sha256 `digest`
+     *
+     * This is how `nodeos` computes the keys provided by the signatures of a transaction.
+     */
+    pub fn recover_digest(&self, digest: &Checksum256) -> Result<PublicKey, CryptoError> {
+        if self.key_type == KeyType::WA {
+            return crate::webauthn::recover(self, digest);
+        }
+        if !self.is_canonical() {
+            return Err(CryptoError::NonCanonicalSignature);
+        }
+        let header = self.data[0];
+        if !(27..35).contains(&header) {
+            return Err(CryptoError::BadRecoveryId(header));
+        }
+        let recid = (header - 27) & 3;
+        let rs = &self.data[1..SIGNATURE_SIZE];
+        let data = match self.key_type {
+            KeyType::K1 => crate::ecc::k1::recover(&digest.data, rs, recid),
+            _ => crate::ecc::r1::recover(&digest.data, rs, recid),
+        };
+        let data = data.ok_or(CryptoError::RecoveryFailed)?;
+        Ok(PublicKey {
+            key_type: self.key_type,
+            data,
+        })
+    }
+
+    /// Recovers the public key that signed `data`, hashed with sha256
+    pub fn recover(&self, data: &[u8]) -> Result<PublicKey, CryptoError> {
+        self.recover_digest(&crate::sha256(data))
+    }
+
+    /// Verifies the signature of an arbitrary message on `chain_id` against `key`
+    #[must_use]
+    pub fn verify_message(&self, chain_id: &Checksum256, data: &[u8], key: &PublicKey) -> bool {
+        self.verify_digest(&crate::message_digest(chain_id, data), key)
+    }
+
+    /// Recovers the public key that signed an arbitrary message on `chain_id`
+    pub fn recover_message(&self, chain_id: &Checksum256, data: &[u8]) -> Result<PublicKey, CryptoError> {
+        self.recover_digest(&crate::message_digest(chain_id, data))
+    }
+}
+
+impl FromStr for Signature {
+    type Err = ParseError;
+
+    /**
+     * Parse Signature from "SIG_K1_...", "SIG_R1_..." or "SIG_WA_..." strings
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseError::BadSignature(s.to_string());
+        let (key_type, encoded) = s.strip_prefix("SIG_").and_then(|rest| rest.split_once('_')).ok_or_else(err)?;
+        let key_type = KeyType::from_str(key_type).map_err(|_| err())?;
+        let data = decode_key(encoded, key_type.as_str()).ok_or_else(err)?;
+        Signature::from_bytes(key_type, &data).map_err(|_| err())
+    }
+}
+
+impl From<&str> for Signature {
+    fn from(str: &str) -> Self {
+        Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse signature: {}", e))
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SIG_{}_{}", self.key_type, encode_key(&self.data, self.key_type.as_str()))
+    }
+}
+
+impl Pack for Signature {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.key_type.index(), out);
+        out.extend_from_slice(&self.data);
+    }
+}
+
+impl Unpack for Signature {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let key_type = KeyType::from_index(unpack_varuint32(data)?)?;
+        Signature::unpack_data(key_type, data)
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(Signature);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::to_hex;
+
+    const K1: &str = "SIG_K1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx";
+    const K1_HEX: &str = "2056355ed1079822d2728886b449f0f4a2bbf48bf38698c0ebe8c7079768882b1c64ac07d7a4bd85cf96b8a74fdcafef1a4805f946177c609fdf31abe2463038e5";
+
+    #[test]
+    fn test_from_str() {
+        let sig = Signature::from_str(K1).unwrap();
+        assert_eq!(sig.key_type, KeyType::K1);
+        assert_eq!(to_hex(&sig.data), K1_HEX);
+        assert_eq!(sig.to_string(), K1);
+
+        for s in [
+            "",
+            "SIG_K1_",
+            "SIG_K1",
+            "SIG_R1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx",
+        ] {
+            assert_eq!(Signature::from_str(s), Err(ParseError::BadSignature(s.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_webauthn() {
+        let mut data = Signature::from(K1).data;
+        vec![0x49_u8; 37].pack(&mut data);
+        r#"{"type":"webauthn.get"}"#.pack(&mut data);
+        let sig = Signature::from_bytes(KeyType::WA, &data).unwrap();
+        assert!(sig.to_string().starts_with("SIG_WA_"));
+        assert_eq!(sig.auth_data().unwrap(), vec![0x49_u8; 37]);
+        assert_eq!(sig.client_json().unwrap(), r#"{"type":"webauthn.get"}"#);
+        assert_eq!(Signature::from(K1).client_json(), None);
+        assert_eq!(Signature::from_str(&sig.to_string()), Ok(sig.clone()));
+        assert_eq!(crate::unpack::<Signature>(&sig.packed()), Ok(sig));
+        assert_eq!(
+            Signature::from_bytes(KeyType::K1, &data),
+            Err(UnpackError::TrailingBytes(data.len() - SIGNATURE_SIZE))
+        );
+    }
+
+    #[test]
+    fn test_pack() {
+        let sig = Signature::from(K1);
+        assert_eq!(to_hex(&sig.packed()), format!("00{}", K1_HEX));
+        assert_eq!(crate::unpack::<Signature>(&sig.packed()), Ok(sig));
+        assert_eq!(crate::unpack::<Signature>(&[1; 65]), Err(UnpackError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_is_canonical() {
+        let sig = Signature::from(K1);
+        assert!(sig.is_canonical());
+        for (i, byte) in [(1, 0x80), (33, 0x80), (1, 0x00), (33, 0x00)] {
+            let mut data = sig.data.clone();
+            data[i] = byte;
+            data[i + 1] = 0x01;
+            assert!(!Signature::from_bytes(KeyType::K1, &data).unwrap().is_canonical());
+            assert!(Signature::from_bytes(KeyType::R1, &data).unwrap().is_canonical());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn test_verify() {
+        use crate::{sha256, PrivateKey, PublicKey};
+
+        let digest = sha256(b"hello");
+        let k1 = Signature::from("SIG_K1_JvbC2s8TwpT129qLAECPazzHvZhCcbd25AigXB885Zv9CxDEgcdyxnUQaQ5XKNA1dGbjZSFZdMFyW21K8dR66Hc6BXkE2e");
+        let k1_key = PublicKey::from("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV");
+        assert!(k1.verify_digest(&digest, &k1_key));
+        assert!(k1.verify(b"hello", &k1_key));
+        assert!(!k1.verify(b"hello!", &k1_key));
+
+        let r1 = Signature::from("SIG_R1_KGi4Q82D4qmiYPH6LCU68hYyXfkZx9vaLG8vx7DvwwEa7H3EUJuxhNjNcWoRQwSwhEdaXp1k4M4LPdQnKebzkaykd11ZR1");
+        let r1_key = PublicKey::from("PUB_R1_5WKbPCbDSvpmYEKzxnZXLVpcVVxWHZDWDNdhUapQwTUDsb5GBV");
+        assert!(r1.verify_digest(&digest, &r1_key));
+        assert!(!r1.verify_digest(&digest, &k1_key));
+        assert!(!k1.verify_digest(&digest, &r1_key));
+
+        assert_eq!(k1.recover_digest(&digest), Ok(k1_key.clone()));
+        assert_eq!(r1.recover(b"hello"), Ok(r1_key.clone()));
+        assert_ne!(k1.recover(b"hello!"), Ok(k1_key.clone()));
+
+        let other = PrivateKey::from("PVT_K1_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V");
+        let mut data = k1.data.clone();
+        data[1] |= 0x80;
+        let non_canonical = Signature::from_bytes(KeyType::K1, &data).unwrap();
+        assert!(!non_canonical.verify_digest(&digest, &other.public_key()));
+        assert_eq!(non_canonical.recover_digest(&digest), Err(CryptoError::NonCanonicalSignature));
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn test_recover() {
+        use crate::{sha256, PrivateKey};
+
+        for key_type in [KeyType::K1, KeyType::R1] {
+            let key = PrivateKey::generate(key_type, &mut rand_core::OsRng);
+            for i in 0..16_u8 {
+                let sig = key.sign(&[i]);
+                assert_eq!(sig.recover(&[i]), Ok(key.public_key()));
+            }
+        }
+
+        // the recovery id must be in 27..35, and both compressed and uncompressed headers are accepted
+        let sig = Signature::from(K1);
+        let digest = sha256(b"");
+        for header in [26, 35] {
+            let mut data = sig.data.clone();
+            data[0] = header;
+            let sig = Signature::from_bytes(KeyType::K1, &data).unwrap();
+            assert_eq!(sig.recover_digest(&digest), Err(CryptoError::BadRecoveryId(header)));
+        }
+        let mut data = sig.data.clone();
+        data[0] -= 4;
+        let uncompressed = Signature::from_bytes(KeyType::K1, &data).unwrap();
+        assert_eq!(uncompressed.recover_digest(&digest), sig.recover_digest(&digest));
+
+        let mut data = sig.data.clone();
+        data[1..33].copy_from_slice(&[0x7f; 32]);
+        data[33..].copy_from_slice(&[0; 32]);
+        let zero_s = Signature::from_bytes(KeyType::K1, &data).unwrap();
+        assert_eq!(zero_s.recover_digest(&digest), Err(CryptoError::NonCanonicalSignature));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let sig = Signature::from(K1);
+        assert_eq!(serde_json::to_string(&sig).unwrap(), format!(r#""{}""#, K1));
+        assert_eq!(serde_json::from_str::<Signature>(&format!(r#""{}""#, K1)).unwrap(), sig);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ParseError, SymbolCode};
+use crate::{Pack, ParseError, SymbolCode, Unpack, UnpackError};
 
 use std::cmp::{Ord, PartialEq, PartialOrd};
 use std::convert::From;
@@ -118,7 +118,6 @@ impl FromStr for Symbol {
 
 impl From<&str> for Symbol {
     #[inline]
-    #[must_use]
     fn from(str: &str) -> Self {
         Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse symbol: {}", e))
     }
@@ -126,7 +125,6 @@ impl From<&str> for Symbol {
 
 impl From<u64> for Symbol {
     #[inline]
-    #[must_use]
     fn from(value: u64) -> Self {
         Symbol { value }
     }
@@ -134,7 +132,6 @@ impl From<u64> for Symbol {
 
 impl From<Symbol> for u64 {
     #[inline]
-    #[must_use]
     fn from(sym: Symbol) -> Self {
         sym.value
     }
@@ -142,7 +139,6 @@ impl From<Symbol> for u64 {
 
 impl AsRef<Symbol> for Symbol {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &Symbol {
         self
     }
@@ -150,12 +146,28 @@ impl AsRef<Symbol> for Symbol {
 
 impl From<Symbol> for bool {
     #[inline]
-    #[must_use]
     fn from(sym: Symbol) -> Self {
         sym.raw() != 0
     }
 }
 
+impl Pack for Symbol {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.value.pack(out)
+    }
+}
+
+impl Unpack for Symbol {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(Symbol::from(u64::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(Symbol);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,7 +186,7 @@ mod tests {
         assert_eq!(1, Symbol::from(1).raw());
         assert_eq!(u64::MAX, Symbol::from(u64::MAX).raw());
 
-        assert_eq!(0 as u64, Symbol::from(0).into());
+        assert_eq!(0_u64, u64::from(Symbol::from(0)));
     }
 
     #[test]
@@ -247,11 +259,11 @@ mod tests {
     #[test]
     fn test_cdt_7() {
         // constexpr explicit operator bool()const
-        assert_eq!(false, Symbol::from(0).into());
-        assert_eq!(true, Symbol::from(1).into());
+        assert_eq!(false, bool::from(Symbol::from(0)));
+        assert_eq!(true, bool::from(Symbol::from(1)));
 
-        assert_eq!(false, Symbol::from_precision(SymbolCode::from(""), 0).into());
-        assert_eq!(true, Symbol::from_precision(SymbolCode::from("SYMBOLL"), 0).into());
+        assert_eq!(false, bool::from(Symbol::from_precision(SymbolCode::from(""), 0)));
+        assert_eq!(true, bool::from(Symbol::from_precision(SymbolCode::from("SYMBOLL"), 0)));
     }
 
     #[test]
@@ -343,4 +355,19 @@ mod tests {
             prop_assert_eq!(sym.to_string(), sym_str);
         }
     }
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(Symbol::from("4,EOS").packed(), [0x04, 0x45, 0x4f, 0x53, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(crate::unpack::<Symbol>(&Symbol::from("4,EOS").packed()), Ok(Symbol::from("4,EOS")));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = Symbol::from("4,EOS");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""4,EOS""#);
+        assert_eq!(serde_json::from_str::<Symbol>(r#""4,EOS""#).unwrap(), value);
+        assert!(serde_json::from_str::<Symbol>(r#""EOS""#).is_err());
+    }
 }
@@ -3,7 +3,7 @@ use std::convert::From;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use crate::ParseError;
+use crate::{Pack, ParseError, Unpack, UnpackError};
 
 /// The `SymbolCode` struct represents a symbol code
 ///
@@ -175,7 +175,6 @@ impl Display for SymbolCode {
 
 impl From<&str> for SymbolCode {
     #[inline]
-    #[must_use]
     fn from(str: &str) -> Self {
         Self::from_str(str).unwrap_or_else(|e| panic!("{}", e))
     }
@@ -204,7 +203,6 @@ impl FromStr for SymbolCode {
 
 impl From<u64> for SymbolCode {
     #[inline]
-    #[must_use]
     fn from(value: u64) -> Self {
         SymbolCode { value }
     }
@@ -212,7 +210,6 @@ impl From<u64> for SymbolCode {
 
 impl From<SymbolCode> for u64 {
     #[inline]
-    #[must_use]
     fn from(symcode: SymbolCode) -> Self {
         symcode.value
     }
@@ -220,7 +217,6 @@ impl From<SymbolCode> for u64 {
 
 impl AsRef<SymbolCode> for SymbolCode {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &SymbolCode {
         self
     }
@@ -228,12 +224,28 @@ impl AsRef<SymbolCode> for SymbolCode {
 
 impl From<SymbolCode> for bool {
     #[inline]
-    #[must_use]
     fn from(symcode: SymbolCode) -> Self {
         symcode.raw() != 0
     }
 }
 
+impl Pack for SymbolCode {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.value.pack(out)
+    }
+}
+
+impl Unpack for SymbolCode {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(SymbolCode::from(u64::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(SymbolCode);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,7 +256,7 @@ mod tests {
         //// constexpr symbol_code()
         // constexpr uint64_t raw()const
         assert_eq!(0, SymbolCode::new().raw());
-        assert_eq!(0 as u64, SymbolCode::new().into());
+        assert_eq!(0_u64, u64::from(SymbolCode::new()));
     }
 
     #[test]
@@ -292,11 +304,11 @@ mod tests {
     #[test]
     fn test_cdt_6() {
         // constexpr explicit operator bool()const
-        assert_eq!(false, SymbolCode::from(0).into());
-        assert_eq!(true, SymbolCode::from(1).into());
+        assert_eq!(false, bool::from(SymbolCode::from(0)));
+        assert_eq!(true, bool::from(SymbolCode::from(1)));
 
-        assert_eq!(false, SymbolCode::from("").into());
-        assert_eq!(true, SymbolCode::from("SYMBOL").into());
+        assert_eq!(false, bool::from(SymbolCode::from("")));
+        assert_eq!(true, bool::from(SymbolCode::from("SYMBOL")));
     }
 
     #[test]
@@ -417,7 +429,7 @@ mod tests {
     fn test_is_valid() {
         assert_eq!(false, SymbolCode::new().is_valid());
         assert_eq!(false, SymbolCode::from(0).is_valid());
-        assert_eq!(false, SymbolCode::from(std::u64::MAX).is_valid());
+        assert_eq!(false, SymbolCode::from(u64::MAX).is_valid());
         assert_eq!(true, SymbolCode::from(5197638).is_valid());
         assert_eq!(true, SymbolCode::from("FOO").is_valid());
     }
@@ -496,9 +508,9 @@ mod tests {
 
     #[test]
     fn test_to_bool() {
-        assert_eq!(true, SymbolCode::from("ABCDEFG").into());
-        assert_eq!(false, SymbolCode::default().into());
-        assert_eq!(false, SymbolCode::from("").into());
+        assert_eq!(true, bool::from(SymbolCode::from("ABCDEFG")));
+        assert_eq!(false, bool::from(SymbolCode::default()));
+        assert_eq!(false, bool::from(SymbolCode::from("")));
     }
 
     proptest! {
@@ -508,4 +520,22 @@ mod tests {
             prop_assert_eq!(symcode.to_string(), input);
         }
     }
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(SymbolCode::from("EOS").packed(), [0x45, 0x4f, 0x53, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            crate::unpack::<SymbolCode>(&SymbolCode::from("EOS").packed()),
+            Ok(SymbolCode::from("EOS"))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = SymbolCode::from("EOS");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""EOS""#);
+        assert_eq!(serde_json::from_str::<SymbolCode>(r#""EOS""#).unwrap(), value);
+        assert!(serde_json::from_str::<SymbolCode>(r#""eos""#).is_err());
+    }
 }
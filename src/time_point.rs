@@ -1,4 +1,6 @@
-use crate::{Microseconds, TimePointSec};
+use std::str::FromStr;
+
+use crate::{Microseconds, Pack, ParseError, TimePointSec, Unpack, UnpackError};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
 pub struct TimePoint {
@@ -27,9 +29,23 @@ impl TimePoint {
     }
 }
 
+impl FromStr for TimePoint {
+    type Err = ParseError;
+
+    /**
+     * Parse TimePoint from string formatted as "2018-03-21T13:08:08.500"
+     *
+     * The fractional part is optional.
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nanos = crate::time_point_sec::parse_iso_string(s)?.unix_timestamp_nanos();
+        let micros = i64::try_from(nanos / 1000).map_err(|_| ParseError::BadTimePoint(s.to_string()))?;
+        Ok(TimePoint::from(Microseconds::from(micros)))
+    }
+}
+
 impl From<Microseconds> for TimePoint {
     #[inline]
-    #[must_use]
     fn from(elapsed: Microseconds) -> Self {
         TimePoint { elapsed }
     }
@@ -43,7 +59,6 @@ impl From<TimePointSec> for TimePoint {
 
 impl AsRef<TimePoint> for TimePoint {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &TimePoint {
         self
     }
@@ -125,6 +140,38 @@ impl std::ops::Sub<TimePointSec> for TimePoint {
     }
 }
 
+impl Pack for TimePoint {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.elapsed.pack(out)
+    }
+}
+
+impl Unpack for TimePoint {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(TimePoint::from(Microseconds::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TimePoint {
+    /**
+     * Serializes the TimePoint as "%Y-%m-%dT%H:%M:%S.%3f", the format used by nodeos
+     */
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = self.elapsed.count().rem_euclid(1_000_000) / 1000;
+        serializer.collect_str(&format_args!("{}.{:03}", self, millis))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TimePoint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serde_helpers::deserialize_from_str(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +336,37 @@ mod tests {
         let tp = TimePoint::from(Microseconds::from(100));
         assert_eq!((tp - Microseconds::from(50)).elapsed, Microseconds::from(50));
     }
+
+    #[test]
+    fn test_pack() {
+        // 2018-06-15T19:17:47
+        let tp = TimePoint::from(Microseconds::from(1529090267000000));
+        assert_eq!(tp.packed(), [0xc0, 0xac, 0x31, 0x12, 0xb3, 0x6e, 0x05, 0x00]);
+        assert_eq!(crate::unpack::<TimePoint>(&tp.packed()), Ok(tp));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "2018-06-15T19:17:47.500".parse::<TimePoint>().unwrap().elapsed.count(),
+            1529090267500000
+        );
+        assert_eq!(
+            "2018-06-15T19:17:47".parse::<TimePoint>().unwrap().elapsed.count(),
+            1529090267000000
+        );
+        assert_eq!(
+            "2018-06-15 19:17:47".parse::<TimePoint>(),
+            Err(ParseError::BadTimePoint("2018-06-15 19:17:47".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = TimePoint::from(Microseconds::from(1529090267500000));
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""2018-06-15T19:17:47.500""#);
+        assert_eq!(serde_json::from_str::<TimePoint>(r#""2018-06-15T19:17:47.500""#).unwrap(), value);
+        assert!(serde_json::from_str::<TimePoint>("1529090267").is_err());
+    }
 }
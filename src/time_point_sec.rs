@@ -2,10 +2,11 @@
 use core::str;
 use std::cmp::{Ord, Ordering, PartialEq, PartialOrd};
 use std::convert::From;
+use std::str::FromStr;
 
 use time::{format_description, OffsetDateTime};
 
-use crate::{check, Microseconds, TimePoint};
+use crate::{check, Microseconds, Pack, ParseError, TimePoint, Unpack, UnpackError};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
 pub struct TimePointSec {
@@ -30,10 +31,30 @@ impl TimePointSec {
     }
 
     pub fn from_iso_string(str: &str) -> Self {
-        let dt = OffsetDateTime::parse(format!("{str}Z").as_str(), &format_description::well_known::Iso8601::DEFAULT)
-            .expect("date parsing failed");
+        Self::from_str(str).expect("date parsing failed")
+    }
+}
+
+/**
+ * Parses a UTC date in the form of "%Y-%m-%dT%H:%M:%S", optionally followed by fractional seconds and a "Z" suffix
+ */
+pub(crate) fn parse_iso_string(s: &str) -> Result<OffsetDateTime, ParseError> {
+    let utc = format!("{}Z", s.strip_suffix('Z').unwrap_or(s));
+    OffsetDateTime::parse(&utc, &format_description::well_known::Iso8601::DEFAULT).map_err(|_| ParseError::BadTimePoint(s.to_string()))
+}
+
+impl FromStr for TimePointSec {
+    type Err = ParseError;
 
-        TimePointSec::from(dt.unix_timestamp() as u32)
+    /**
+     * Parse TimePointSec from string formatted as "2018-03-21T13:08:08"
+     *
+     * Fractional seconds are truncated.
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let seconds = parse_iso_string(s)?.unix_timestamp();
+        let utc_seconds = u32::try_from(seconds).map_err(|_| ParseError::BadTimePoint(s.to_string()))?;
+        Ok(TimePointSec { utc_seconds })
     }
 }
 
@@ -57,7 +78,7 @@ impl std::fmt::Display for TimePointSec {
      */
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let dt = OffsetDateTime::from_unix_timestamp(self.sec_since_epoch() as i64).expect("format failed");
-        let format = format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]").unwrap();
+        let format = format_description::parse_borrowed::<2>("[year]-[month]-[day]T[hour]:[minute]:[second]").unwrap();
         write!(f, "{}", dt.format(&format).unwrap())
     }
 }
@@ -143,6 +164,23 @@ impl std::ops::Sub for TimePointSec {
     }
 }
 
+impl Pack for TimePointSec {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.utc_seconds.pack(out)
+    }
+}
+
+impl Unpack for TimePointSec {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(TimePointSec::from(u32::unpack(data)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_helpers::impl_serde_string!(TimePointSec);
+
 #[cfg(test)]
 mod tests {
     use crate::seconds;
@@ -327,4 +365,20 @@ mod tests {
         let tp1 = TimePointSec::from(100);
         assert_eq!((tp1 - Microseconds::from(50_000_000)).sec_since_epoch(), 50);
     }
+
+    #[test]
+    fn test_pack() {
+        let tps = TimePointSec::from_iso_string("2018-06-15T19:17:47");
+        assert_eq!(tps.packed(), [0xdb, 0x10, 0x24, 0x5b]);
+        assert_eq!(crate::unpack::<TimePointSec>(&tps.packed()), Ok(tps));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let value = TimePointSec::from(1529090267);
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""2018-06-15T19:17:47""#);
+        assert_eq!(serde_json::from_str::<TimePointSec>(r#""2018-06-15T19:17:47""#).unwrap(), value);
+        assert!(serde_json::from_str::<TimePointSec>(r#""2018-13-15T19:17:47""#).is_err());
+    }
 }
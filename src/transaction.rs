@@ -0,0 +1,413 @@
+use crate::serializer::impl_pack_struct;
+use crate::{pack, seconds, sha256, Action, Bytes, Checksum256, Signature, TimePoint, TimePointSec, VarUint32};
+#[cfg(feature = "crypto")]
+use crate::{CryptoError, PrivateKey, PublicKey};
+
+/// The maximum lifetime of a transaction in seconds, the default of `nodeos`
+pub const MAX_TRANSACTION_LIFETIME: u32 = 60 * 60;
+
+/// Returns the block number of a block id, stored big endian in its first 4 bytes
+#[must_use]
+pub fn block_num(block_id: &Checksum256) -> u32 {
+    u32::from_be_bytes(block_id.data[0..4].try_into().unwrap())
+}
+
+/// Returns the `ref_block_num` referencing a block id, the lower 16 bits of its block number
+#[must_use]
+pub fn ref_block_num(block_id: &Checksum256) -> u16 {
+    block_num(block_id) as u16
+}
+
+/// Returns the `ref_block_prefix` referencing a block id, the little endian `u32` at bytes 8 to 12
+#[must_use]
+pub fn ref_block_prefix(block_id: &Checksum256) -> u32 {
+    u32::from_le_bytes(block_id.data[8..12].try_into().unwrap())
+}
+
+/// The `TransactionHeader` struct represents the fields of a transaction limiting where and when it applies
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp>
+///
+/// `ref_block_num` and `ref_block_prefix` reference a recent block (TAPOS), so the transaction
+/// is only valid on the fork containing it.
+#[derive(Eq, Copy, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionHeader {
+    /// The time after which the transaction is rejected
+    pub expiration: TimePointSec,
+    /// The lower 16 bits of the referenced block number
+    pub ref_block_num: u16,
+    /// The lower 32 bits of the second 64-bit word of the referenced block id
+    pub ref_block_prefix: u32,
+    /// The maximum net usage, in 8-byte words, 0 for no limit besides the account limits
+    pub max_net_usage_words: VarUint32,
+    /// The maximum cpu usage in milliseconds, 0 for no limit besides the account limits
+    pub max_cpu_usage_ms: u8,
+    /// The number of seconds the transaction is delayed by
+    pub delay_sec: VarUint32,
+}
+
+impl_pack_struct!(TransactionHeader {
+    expiration,
+    ref_block_num,
+    ref_block_prefix,
+    max_net_usage_words,
+    max_cpu_usage_ms,
+    delay_sec
+});
+
+impl TransactionHeader {
+    /**
+     * References `block_id` for TAPOS, like `transaction_header::set_reference_block`
+     *
+     * Use a recent irreversible block, so the transaction is valid on every fork.
+     */
+    pub fn set_reference_block(&mut self, block_id: &Checksum256) {
+        self.ref_block_num = ref_block_num(block_id);
+        self.ref_block_prefix = ref_block_prefix(block_id);
+    }
+
+    /// Returns true if the header references `block_id`
+    #[must_use]
+    pub fn verify_reference_block(&self, block_id: &Checksum256) -> bool {
+        self.ref_block_num == ref_block_num(block_id) && self.ref_block_prefix == ref_block_prefix(block_id)
+    }
+
+    /// Sets the expiration `seconds` after the head block time
+    pub fn set_expiration(&mut self, head_time: TimePointSec, seconds: u32) {
+        self.expiration = head_time + seconds;
+    }
+
+    /**
+     * Returns true if a block produced at `now` can include the transaction
+     *
+     * Like `nodeos`, the transaction must not have expired yet, and its expiration must be at most
+     * [`MAX_TRANSACTION_LIFETIME`] seconds after `now`.
+     */
+    #[must_use]
+    pub fn is_expiration_valid(&self, now: TimePoint) -> bool {
+        let expiration = TimePoint::from(self.expiration);
+        now <= expiration && expiration <= now + seconds(MAX_TRANSACTION_LIFETIME as i64)
+    }
+}
+
+/// The `Transaction` struct represents an unsigned transaction
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp>
+///
+/// In JSON the header fields are inlined, like `nodeos` shows them.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Action, Name, Pack, PermissionLevel, TimePointSec, Transaction};
+///
+/// let mut trx = Transaction::default();
+/// trx.header.expiration = TimePointSec::from_iso_string("2024-01-01T00:00:30");
+/// trx.actions.push(Action::new(Name::from("eosio"), Name::from("refund"), vec![PermissionLevel::from("alice@active")], &Name::from("alice")));
+/// assert_eq!(13 + 2 + 42 + 1, trx.packed().len());
+/// assert_eq!("b4cb44b92d6d3c6ea4382fc4f403290bf9f2004e74c301c923b991de1c32e031", trx.id().to_string());
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transaction {
+    /// The header of the transaction
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub header: TransactionHeader,
+    /// Actions executed without authorization or access to state, such as `onerror` handlers
+    pub context_free_actions: Vec<Action>,
+    /// The actions of the transaction
+    pub actions: Vec<Action>,
+    /// Extensions as `(type, data)` pairs
+    pub transaction_extensions: Vec<(u16, Bytes)>,
+}
+
+impl Transaction {
+    /// Creates a transaction executing `actions`
+    #[must_use]
+    pub fn new(header: TransactionHeader, actions: Vec<Action>) -> Self {
+        Transaction {
+            header,
+            actions,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the transaction id, the sha256 of the packed transaction
+    #[must_use]
+    pub fn id(&self) -> Checksum256 {
+        sha256(&pack(self))
+    }
+
+    /**
+     * Returns the digest signed by the keys authorizing the transaction on `chain_id`
+     *
+     * The digest is `sha256(chain_id || packed transaction || cfd hash)`, where the cfd hash is
+     * the sha256 of the packed `context_free_data`, or 32 zero bytes when there is none.
+     */
+    #[must_use]
+    pub fn signing_digest(&self, chain_id: &Checksum256, context_free_data: &[Bytes]) -> Checksum256 {
+        let cfd_hash = match context_free_data.is_empty() {
+            true => Checksum256::default(),
+            false => sha256(&pack(context_free_data)),
+        };
+        sha256(&[chain_id.data.as_slice(), &pack(self), &cfd_hash.data].concat())
+    }
+}
+
+impl_pack_struct!(Transaction {
+    header,
+    context_free_actions,
+    actions,
+    transaction_extensions
+});
+
+/// The `SignedTransaction` struct represents a transaction with its signatures and context free data
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp>
+///
+/// In JSON the transaction fields are inlined, like `nodeos` shows them.
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedTransaction {
+    /// The signed transaction
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub transaction: Transaction,
+    /// The signatures of the transaction
+    pub signatures: Vec<Signature>,
+    /// The data of the context free actions, one entry per action
+    pub context_free_data: Vec<Bytes>,
+}
+
+impl SignedTransaction {
+    /// Wraps a transaction without signatures or context free data
+    #[must_use]
+    pub fn new(transaction: Transaction) -> Self {
+        SignedTransaction {
+            transaction,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the transaction id, which doesn't depend on the signatures or context free data
+    #[must_use]
+    pub fn id(&self) -> Checksum256 {
+        self.transaction.id()
+    }
+
+    /// Returns the digest signed by the keys authorizing the transaction on `chain_id`
+    #[must_use]
+    pub fn signing_digest(&self, chain_id: &Checksum256) -> Checksum256 {
+        self.transaction.signing_digest(chain_id, &self.context_free_data)
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl SignedTransaction {
+    /// Signs the transaction for `chain_id` with `key`, appending the signature
+    pub fn sign(&mut self, key: &PrivateKey, chain_id: &Checksum256) {
+        let signature = key.sign_digest(&self.signing_digest(chain_id));
+        self.signatures.push(signature);
+    }
+
+    /**
+     * Recovers the public keys that signed the transaction for `chain_id`
+     *
+     * These are the keys `nodeos` checks against the authorizations of the actions.
+     */
+    pub fn recover_keys(&self, chain_id: &Checksum256) -> Result<Vec<PublicKey>, CryptoError> {
+        let digest = self.signing_digest(chain_id);
+        self.signatures.iter().map(|signature| signature.recover_digest(&digest)).collect()
+    }
+}
+
+impl_pack_struct!(SignedTransaction {
+    transaction,
+    signatures,
+    context_free_data
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{unpack, Name, Pack, PermissionLevel};
+
+    const CHAIN_ID: &str = "aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906";
+
+    fn transaction() -> Transaction {
+        let header = TransactionHeader {
+            expiration: TimePointSec::from_iso_string("2018-06-15T19:17:47"),
+            ref_block_num: 1234,
+            ref_block_prefix: 0x01020304,
+            max_net_usage_words: VarUint32::from(128),
+            max_cpu_usage_ms: 5,
+            delay_sec: VarUint32::from(0),
+        };
+        Transaction::new(
+            header,
+            vec![Action::new(
+                Name::from("eosio"),
+                Name::from("refund"),
+                vec![PermissionLevel::from("alice@active")],
+                &Name::from("alice"),
+            )],
+        )
+    }
+
+    #[test]
+    fn test_pack() {
+        let trx = transaction();
+        let packed = trx.packed();
+        assert_eq!(
+            crate::bytes::to_hex(&packed[..16]),
+            concat!("db10245b", "d204", "04030201", "8001", "05", "00", "00", "01")
+        );
+        assert_eq!(packed.len(), 16 + 42 + 1);
+        assert_eq!(*packed.last().unwrap(), 0);
+        assert_eq!(unpack::<Transaction>(&packed), Ok(trx.clone()));
+
+        let mut signed = SignedTransaction::new(trx.clone());
+        signed.signatures.push(Signature::from(
+            "SIG_K1_Kg2UKjXTX48gw2wWH4zmsZmWu3yarcfC21Bd9JPj7QoDURqiAacCHmtExPk3syPb2tFLsp1R4ttXLXgr7FYgDvKPC5RCkx",
+        ));
+        signed.context_free_data.push(Bytes::from(vec![1, 2]));
+        let packed_signed = signed.packed();
+        assert_eq!(&packed_signed[..packed.len()], packed.as_slice());
+        assert_eq!(packed_signed.len(), packed.len() + 1 + 66 + 1 + 3);
+        assert_eq!(unpack::<SignedTransaction>(&packed_signed), Ok(signed));
+    }
+
+    #[test]
+    fn test_tapos() {
+        // the block number is big endian, the prefix little endian
+        let block_id = Checksum256::from("000003e8b5c0a0b7f6dc5f2bcc1b3b43d6a2d4cf1dd4a7b4e2e5a6a09ae4c0e6");
+        assert_eq!(block_num(&block_id), 1000);
+        assert_eq!(ref_block_num(&block_id), 1000);
+        assert_eq!(ref_block_prefix(&block_id), 0x2b5fdcf6);
+
+        let block_id = Checksum256::from("0bd2ef3ae2c13fe2c1e8bd4a21e64cf0fcaf2b40ecec6bc12f4a2c6f3b35a7a6");
+        assert_eq!(block_num(&block_id), 198373178);
+        assert_eq!(ref_block_num(&block_id), 0xef3a);
+        assert_eq!(ref_block_prefix(&block_id), 0x4abde8c1);
+
+        let mut header = TransactionHeader::default();
+        header.set_reference_block(&block_id);
+        assert_eq!((header.ref_block_num, header.ref_block_prefix), (61242, 1253959873));
+        assert!(header.verify_reference_block(&block_id));
+        assert!(!header.verify_reference_block(&Checksum256::default()));
+    }
+
+    #[test]
+    fn test_expiration() {
+        let head_time = TimePointSec::from_iso_string("2024-01-01T00:00:00");
+        let mut header = TransactionHeader::default();
+        header.set_expiration(head_time, 30);
+        assert_eq!(header.expiration, TimePointSec::from_iso_string("2024-01-01T00:00:30"));
+
+        let now = TimePoint::from(head_time);
+        assert!(header.is_expiration_valid(now));
+        assert!(header.is_expiration_valid(TimePoint::from_iso_string("2024-01-01T00:00:30")));
+        assert!(!header.is_expiration_valid("2024-01-01T00:00:30.500".parse().unwrap()));
+        assert!(!header.is_expiration_valid(TimePoint::from_iso_string("2024-01-01T00:01:00")));
+
+        // the expiration can't be too far in the future either
+        header.set_expiration(head_time, MAX_TRANSACTION_LIFETIME);
+        assert!(header.is_expiration_valid(now));
+        header.set_expiration(head_time, MAX_TRANSACTION_LIFETIME + 1);
+        assert!(!header.is_expiration_valid(now));
+        assert!(header.is_expiration_valid(now + seconds(1)));
+    }
+
+    #[test]
+    fn test_id() {
+        assert_eq!(
+            Transaction::default().id(),
+            Checksum256::from("374708fff7719dd5979ec875d56cd2286f6d3cf7ec317a3b25632aab28ec37bb")
+        );
+        let trx = transaction();
+        let id = Checksum256::from("7e29df84f9e6933f2345f1df489aa61c8ff584971ae93eeb9412b715f8ee2919");
+        assert_eq!(trx.id(), id);
+
+        // signatures and context free data are not part of the id
+        let mut signed = SignedTransaction::new(trx);
+        signed.context_free_data.push(Bytes::from(vec![1, 2]));
+        assert_eq!(signed.id(), id);
+    }
+
+    #[test]
+    fn test_signing_digest() {
+        let chain_id = Checksum256::from(CHAIN_ID);
+        let trx = transaction();
+        assert_eq!(
+            trx.signing_digest(&chain_id, &[]),
+            Checksum256::from("3943088c0c1baa8eb2a1af06cc5ff0d9808a036f65b1d108a42195ae05bc2a7b")
+        );
+        assert_eq!(
+            trx.signing_digest(&chain_id, &[Bytes::from(vec![1, 2])]),
+            Checksum256::from("451975191d1e8c680bf8763103079b5f6da749cd1913524ea8e00d813f44d75f")
+        );
+        assert_eq!(
+            Transaction::default().signing_digest(&Checksum256::default(), &[]),
+            sha256(&[0; 32 + 16 + 32])
+        );
+        assert_ne!(trx.signing_digest(&Checksum256::default(), &[]), trx.signing_digest(&chain_id, &[]));
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn test_sign() {
+        let chain_id = Checksum256::from(CHAIN_ID);
+        let k1 = PrivateKey::from("5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3");
+        let r1 = PrivateKey::from("PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx");
+        let mut signed = SignedTransaction::new(transaction());
+        signed.context_free_data.push(Bytes::from(vec![1, 2]));
+        signed.sign(&k1, &chain_id);
+        signed.sign(&r1, &chain_id);
+        assert_eq!(signed.recover_keys(&chain_id), Ok(vec![k1.public_key(), r1.public_key()]));
+        assert!(signed.signatures[0].verify_digest(&signed.signing_digest(&chain_id), &k1.public_key()));
+
+        // the signatures commit to the chain and the context free data
+        assert_ne!(
+            signed.recover_keys(&Checksum256::default()),
+            Ok(vec![k1.public_key(), r1.public_key()])
+        );
+        signed.context_free_data.clear();
+        assert!(!signed.signatures[0].verify_digest(&signed.signing_digest(&chain_id), &k1.public_key()));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let trx = transaction();
+        let json = serde_json::to_value(&trx).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "expiration": "2018-06-15T19:17:47",
+                "ref_block_num": 1234,
+                "ref_block_prefix": 16909060,
+                "max_net_usage_words": 128,
+                "max_cpu_usage_ms": 5,
+                "delay_sec": 0,
+                "context_free_actions": [],
+                "actions": [{
+                    "account": "eosio",
+                    "name": "refund",
+                    "authorization": [{"actor": "alice", "permission": "active"}],
+                    "data": "0000000000855c34"
+                }],
+                "transaction_extensions": []
+            })
+        );
+        assert_eq!(serde_json::from_value::<Transaction>(json).unwrap(), trx);
+
+        let mut signed = SignedTransaction::new(trx);
+        signed.transaction.transaction_extensions.push((1, Bytes::from(vec![0xab])));
+        let json = serde_json::to_value(&signed).unwrap();
+        assert_eq!(json["signatures"], serde_json::json!([]));
+        assert_eq!(json["context_free_data"], serde_json::json!([]));
+        assert_eq!(json["transaction_extensions"], serde_json::json!([[1, "ab"]]));
+        assert_eq!(json["ref_block_num"], 1234);
+        assert_eq!(serde_json::from_value::<SignedTransaction>(json).unwrap(), signed);
+    }
+}
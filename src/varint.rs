@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::{pack_varuint32, unpack_varuint32, Pack, Unpack, UnpackError};
+
+/// The `VarUint32` struct represents a variable-length unsigned 32-bit integer
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/varint.hpp>
+///
+/// Packed as LEB128, using one to five bytes.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Pack, VarUint32};
+///
+/// assert_eq!(vec![0x80, 0x01], VarUint32::from(128).packed());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct VarUint32 {
+    /// The underlying value
+    pub value: u32,
+}
+
+/// The `VarInt32` struct represents a variable-length signed 32-bit integer
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/varint.hpp>
+///
+/// Zigzag encoded, then packed as LEB128.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Pack, VarInt32};
+///
+/// assert_eq!(vec![0x01], VarInt32::from(-1).packed());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct VarInt32 {
+    /// The underlying value
+    pub value: i32,
+}
+
+impl From<u32> for VarUint32 {
+    #[inline]
+    fn from(value: u32) -> Self {
+        VarUint32 { value }
+    }
+}
+
+impl From<VarUint32> for u32 {
+    #[inline]
+    fn from(v: VarUint32) -> Self {
+        v.value
+    }
+}
+
+impl From<i32> for VarInt32 {
+    #[inline]
+    fn from(value: i32) -> Self {
+        VarInt32 { value }
+    }
+}
+
+impl From<VarInt32> for i32 {
+    #[inline]
+    fn from(v: VarInt32) -> Self {
+        v.value
+    }
+}
+
+impl fmt::Display for VarUint32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl fmt::Display for VarInt32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Pack for VarUint32 {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.value, out)
+    }
+}
+
+impl Unpack for VarUint32 {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(VarUint32::from(unpack_varuint32(data)?))
+    }
+}
+
+impl Pack for VarInt32 {
+    #[inline]
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(((self.value << 1) ^ (self.value >> 31)) as u32, out)
+    }
+}
+
+impl Unpack for VarInt32 {
+    #[inline]
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        let v = unpack_varuint32(data)?;
+        Ok(VarInt32::from(((v >> 1) as i32) ^ -((v & 1) as i32)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::to_hex;
+
+    #[test]
+    fn test_pack() {
+        let cases: [(u32, &str); 4] = [(0, "00"), (127, "7f"), (300, "ac02"), (u32::MAX, "ffffffff0f")];
+        for (value, expected) in cases {
+            assert_eq!(to_hex(&VarUint32::from(value).packed()), expected);
+            assert_eq!(
+                crate::unpack::<VarUint32>(&VarUint32::from(value).packed()),
+                Ok(VarUint32::from(value))
+            );
+        }
+
+        let cases: [(i32, &str); 6] = [
+            (0, "00"),
+            (-1, "01"),
+            (1, "02"),
+            (-64, "7f"),
+            (64, "8001"),
+            (i32::MIN, "ffffffff0f"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(to_hex(&VarInt32::from(value).packed()), expected);
+            assert_eq!(
+                crate::unpack::<VarInt32>(&VarInt32::from(value).packed()),
+                Ok(VarInt32::from(value))
+            );
+        }
+        assert_eq!(
+            crate::unpack::<VarInt32>(&VarInt32::from(i32::MAX).packed()),
+            Ok(VarInt32::from(i32::MAX))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        assert_eq!(serde_json::to_string(&VarUint32::from(300)).unwrap(), "300");
+        assert_eq!(serde_json::to_string(&VarInt32::from(-300)).unwrap(), "-300");
+        assert_eq!(serde_json::from_str::<VarInt32>("-300").unwrap(), VarInt32::from(-300));
+        assert!(serde_json::from_str::<VarUint32>("-1").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{pack_varuint32, unpack_varuint32, Pack, UnpackBorrowed, UnpackError};
+
+/// The `VecView` struct represents a packed vector borrowed from its input
+///
+/// Unpacking validates every item and keeps the packed bytes, items are decoded again
+/// on iteration instead of being collected into a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{unpack_borrowed, VecView};
+///
+/// let bytes = [0x02, 0x02, b'h', b'i', 0x03, b'y', b'o', b'u'];
+/// let memos = unpack_borrowed::<VecView<&str>>(&bytes).unwrap();
+/// assert_eq!(2, memos.len());
+/// assert_eq!(vec!["hi", "you"], memos.iter().collect::<Vec<_>>());
+/// ```
+pub struct VecView<'a, T> {
+    len: usize,
+    data: &'a [u8],
+    item: PhantomData<T>,
+}
+
+impl<'a, T: UnpackBorrowed<'a>> VecView<'a, T> {
+    /// Returns the number of items
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no items
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the packed items, without the length prefix
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns an iterator decoding the items
+    #[inline]
+    pub fn iter(&self) -> VecViewIter<'a, T> {
+        VecViewIter {
+            remaining: self.len,
+            data: self.data,
+            item: PhantomData,
+        }
+    }
+
+    /// Decodes the items into a `Vec`
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Clone for VecView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for VecView<'_, T> {}
+
+impl<T> PartialEq for VecView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.data == other.data
+    }
+}
+
+impl<T> Eq for VecView<'_, T> {}
+
+impl<'a, T: UnpackBorrowed<'a> + fmt::Debug> fmt::Debug for VecView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: UnpackBorrowed<'a>> IntoIterator for VecView<'a, T> {
+    type Item = T;
+    type IntoIter = VecViewIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: UnpackBorrowed<'a>> IntoIterator for &VecView<'a, T> {
+    type Item = T;
+    type IntoIter = VecViewIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Pack for VecView<'_, T> {
+    fn pack(&self, out: &mut Vec<u8>) {
+        pack_varuint32(self.len as u32, out);
+        out.extend_from_slice(self.data);
+    }
+}
+
+impl<'a, T: UnpackBorrowed<'a>> UnpackBorrowed<'a> for VecView<'a, T> {
+    fn unpack_borrowed(data: &mut &'a [u8]) -> Result<Self, UnpackError> {
+        let len = unpack_varuint32(data)? as usize;
+        let start = *data;
+        for _ in 0..len {
+            T::unpack_borrowed(data)?;
+        }
+        Ok(VecView {
+            len,
+            data: &start[..start.len() - data.len()],
+            item: PhantomData,
+        })
+    }
+}
+
+/// Iterator over the items of a [`VecView`]
+pub struct VecViewIter<'a, T> {
+    remaining: usize,
+    data: &'a [u8],
+    item: PhantomData<T>,
+}
+
+impl<'a, T: UnpackBorrowed<'a>> Iterator for VecViewIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(T::unpack_borrowed(&mut self.data).expect("items are validated when the view is unpacked"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: UnpackBorrowed<'a>> ExactSizeIterator for VecViewIter<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{unpack_borrowed, Asset, Name};
+
+    #[test]
+    fn test_unpack() {
+        let names = vec![Name::from("alice"), Name::from("bob")];
+        let packed = names.packed();
+        let view = unpack_borrowed::<VecView<Name>>(&packed).unwrap();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.as_bytes(), &packed[1..]);
+        assert_eq!(view.to_vec(), names);
+        assert_eq!(view.packed(), packed);
+        assert_eq!(format!("{:?}", view), format!("{:?}", names));
+
+        let empty = unpack_borrowed::<VecView<Asset>>(&[0x00]).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().next(), None);
+
+        assert_eq!(unpack_borrowed::<VecView<Name>>(&packed[..12]), Err(UnpackError::UnexpectedEof));
+        assert_eq!(unpack_borrowed::<VecView<&str>>(&[0x01, 0x01, 0xff]), Err(UnpackError::BadUtf8));
+    }
+
+    #[test]
+    fn test_nested() {
+        let memos = vec![vec!["a".to_string(), "bc".to_string()], vec![], vec!["d".to_string()]];
+        let packed = memos.packed();
+        let view = unpack_borrowed::<VecView<VecView<&str>>>(&packed).unwrap();
+        let items: Vec<Vec<&str>> = view.iter().map(|inner| inner.to_vec()).collect();
+        assert_eq!(items, memos);
+        assert_eq!(view.iter().len(), 3);
+        assert_eq!(view.packed(), packed);
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::bytes::{from_hex, to_hex};
+use crate::{aes_cbc, pack, unpack, Checksum512, Pack, PrivateKey, PublicKey, Unpack, UnpackError, WalletError};
+
+/// The decrypted content of a wallet, `plain_keys` in keosd
+#[derive(PartialEq)]
+struct PlainKeys {
+    checksum: Checksum512,
+    keys: BTreeMap<PublicKey, PrivateKey>,
+}
+
+impl Pack for PlainKeys {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.checksum.pack(out);
+        self.keys.pack(out);
+    }
+}
+
+impl Unpack for PlainKeys {
+    fn unpack(data: &mut &[u8]) -> Result<Self, UnpackError> {
+        Ok(PlainKeys {
+            checksum: Checksum512::unpack(data)?,
+            keys: BTreeMap::unpack(data)?,
+        })
+    }
+}
+
+/// The `Wallet` struct represents a keosd `.wallet` file
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/programs/keosd/soft_wallet.cpp>
+///
+/// The file is the JSON `{"cipher_keys":"<hex>"}`, where `cipher_keys` is the key map, prefixed by
+/// `sha512(password)`, encrypted with AES-256-CBC using that same hash as key and IV. The encrypted
+/// keys are updated on every change, so an unlocked wallet can be saved at any time.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{PrivateKey, Wallet};
+///
+/// let mut wallet = Wallet::new("PW5secret");
+/// let key = wallet.import_key(PrivateKey::from("5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3")).unwrap();
+/// assert_eq!("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV", key.to_string());
+///
+/// let mut wallet = Wallet::from_json(&wallet.to_json()).unwrap();
+/// assert!(wallet.is_locked());
+/// wallet.unlock("PW5secret").unwrap();
+/// assert_eq!(vec![key], wallet.public_keys().unwrap());
+/// ```
+#[derive(PartialEq)]
+pub struct Wallet {
+    cipher_keys: Vec<u8>,
+    plain_keys: Option<PlainKeys>,
+}
+
+impl Wallet {
+    /// Creates an empty wallet protected by `password`, unlocked
+    pub fn new(password: &str) -> Self {
+        let mut wallet = Wallet {
+            cipher_keys: vec![],
+            plain_keys: Some(PlainKeys {
+                checksum: Checksum512::sha512(password.as_bytes()),
+                keys: BTreeMap::new(),
+            }),
+        };
+        wallet.encrypt_keys();
+        wallet
+    }
+
+    /// Parses the JSON content of a `.wallet` file, the wallet starts locked
+    pub fn from_json(json: &str) -> Result<Self, WalletError> {
+        let value: serde_json::Value = serde_json::from_str(json).map_err(|e| WalletError::BadFile(e.to_string()))?;
+        let cipher_keys = value
+            .get("cipher_keys")
+            .and_then(|v| v.as_str())
+            .and_then(from_hex)
+            .ok_or_else(|| WalletError::BadFile("missing or invalid cipher_keys".to_string()))?;
+        Ok(Wallet {
+            cipher_keys,
+            plain_keys: None,
+        })
+    }
+
+    /// Returns the JSON content of the `.wallet` file
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::json!({ "cipher_keys": to_hex(&self.cipher_keys) }).to_string()
+    }
+
+    /// Reads a `.wallet` file, the wallet starts locked
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, WalletError> {
+        let json = std::fs::read_to_string(path).map_err(|e| WalletError::Io(e.to_string()))?;
+        Wallet::from_json(&json)
+    }
+
+    /// Writes the `.wallet` file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), WalletError> {
+        std::fs::write(path, self.to_json()).map_err(|e| WalletError::Io(e.to_string()))
+    }
+
+    /// Returns true if the keys are not decrypted
+    #[inline]
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.plain_keys.is_none()
+    }
+
+    /// Forgets the decrypted keys and the password
+    pub fn lock(&mut self) {
+        self.plain_keys = None;
+    }
+
+    /// Decrypts the keys with `password`
+    pub fn unlock(&mut self, password: &str) -> Result<(), WalletError> {
+        let checksum = Checksum512::sha512(password.as_bytes());
+        let plain_keys = aes_cbc::decrypt(&checksum, &self.cipher_keys)
+            .and_then(|plain| unpack::<PlainKeys>(&plain).ok())
+            .filter(|plain_keys| plain_keys.checksum == checksum)
+            .ok_or(WalletError::InvalidPassword)?;
+        self.plain_keys = Some(plain_keys);
+        Ok(())
+    }
+
+    /// Re-encrypts the keys with a new password, the wallet must be unlocked
+    pub fn set_password(&mut self, password: &str) -> Result<(), WalletError> {
+        self.unlocked_mut()?.checksum = Checksum512::sha512(password.as_bytes());
+        self.encrypt_keys();
+        Ok(())
+    }
+
+    /// Adds a private key, returning its public key
+    pub fn import_key(&mut self, key: PrivateKey) -> Result<PublicKey, WalletError> {
+        let public_key = key.public_key();
+        let keys = &mut self.unlocked_mut()?.keys;
+        if keys.contains_key(&public_key) {
+            return Err(WalletError::KeyExists(public_key.to_string()));
+        }
+        keys.insert(public_key.clone(), key);
+        self.encrypt_keys();
+        Ok(public_key)
+    }
+
+    /// Removes the private key of `public_key`, returning it
+    pub fn remove_key(&mut self, public_key: &PublicKey) -> Result<PrivateKey, WalletError> {
+        let key = self
+            .unlocked_mut()?
+            .keys
+            .remove(public_key)
+            .ok_or_else(|| WalletError::KeyNotFound(public_key.to_string()))?;
+        self.encrypt_keys();
+        Ok(key)
+    }
+
+    /// Returns the key pairs, the wallet must be unlocked
+    pub fn list_keys(&self) -> Result<&BTreeMap<PublicKey, PrivateKey>, WalletError> {
+        Ok(&self.plain_keys.as_ref().ok_or(WalletError::Locked)?.keys)
+    }
+
+    /// Returns the public keys, the wallet must be unlocked
+    pub fn public_keys(&self) -> Result<Vec<PublicKey>, WalletError> {
+        Ok(self.list_keys()?.keys().cloned().collect())
+    }
+
+    /// Returns the private key of `public_key`, the wallet must be unlocked
+    pub fn private_key(&self, public_key: &PublicKey) -> Result<&PrivateKey, WalletError> {
+        self.list_keys()?
+            .get(public_key)
+            .ok_or_else(|| WalletError::KeyNotFound(public_key.to_string()))
+    }
+
+    fn unlocked_mut(&mut self) -> Result<&mut PlainKeys, WalletError> {
+        self.plain_keys.as_mut().ok_or(WalletError::Locked)
+    }
+
+    fn encrypt_keys(&mut self) {
+        if let Some(plain_keys) = &self.plain_keys {
+            self.cipher_keys = aes_cbc::encrypt(&plain_keys.checksum, &pack(plain_keys));
+        }
+    }
+}
+
+impl fmt::Debug for Wallet {
+    /**
+     * Prints the lock state and public keys, keeping the password hash and private keys out of logs
+     */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let public_keys = self
+            .plain_keys
+            .as_ref()
+            .map(|plain_keys| plain_keys.keys.keys().map(ToString::to_string).collect::<Vec<_>>());
+        f.debug_struct("Wallet")
+            .field("locked", &self.is_locked())
+            .field("public_keys", &public_keys)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyType;
+
+    const PASSWORD: &str = "PW5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3";
+    const WIF: &str = "5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3";
+
+    /// A wallet holding the `WIF` key, encrypted with an independent AES implementation
+    const WALLET: &str = r#"{"cipher_keys":"2f4c09a49a70c4c85805c2624ab38f57f158f8b9fba5df1ba21b5932dca5d9b999d5bb02d954a4e6b1da296dc479bb88d66d4cea2a253ace4dad483d8f52b3d3e69ab6fcf2cc82db6a21211404ff7fd2ed2085012bf663d9da99d9c2cd42aeb8b84d0222542d20b42b11bd2643175b7c01be348a82e9ee8eeaa9f0d9a16b491390146ea8044dbf986075337ec73fa641"}"#;
+
+    #[test]
+    fn test_unlock() {
+        let mut wallet = Wallet::from_json(WALLET).unwrap();
+        assert!(wallet.is_locked());
+        assert_eq!(wallet.public_keys(), Err(WalletError::Locked));
+        assert_eq!(wallet.unlock("PW5wrong"), Err(WalletError::InvalidPassword));
+        wallet.unlock(PASSWORD).unwrap();
+        assert_eq!(
+            wallet.public_keys().unwrap(),
+            [PublicKey::from("EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV")]
+        );
+        assert_eq!(wallet.list_keys().unwrap().values().next().unwrap(), &PrivateKey::from(WIF));
+        assert_eq!(
+            format!("{:?}", wallet),
+            r#"Wallet { locked: false, public_keys: Some(["EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV"]) }"#
+        );
+        assert!(!format!("{:?}", wallet).contains(&to_hex(&Checksum512::sha512(PASSWORD.as_bytes()).data)));
+
+        // encryption is deterministic, so the file is unchanged
+        assert_eq!(wallet.to_json(), WALLET);
+        wallet.lock();
+        assert!(wallet.is_locked());
+        assert_eq!(format!("{:?}", wallet), "Wallet { locked: true, public_keys: None }");
+        assert_eq!(wallet.to_json(), WALLET);
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut wallet = Wallet::new(PASSWORD);
+        assert_eq!(wallet.public_keys().unwrap(), []);
+
+        let public_key = wallet.import_key(PrivateKey::from(WIF)).unwrap();
+        assert_eq!(wallet.to_json(), WALLET);
+        assert_eq!(
+            wallet.import_key(PrivateKey::from(WIF)),
+            Err(WalletError::KeyExists(public_key.to_string()))
+        );
+        let r1 = wallet
+            .import_key(PrivateKey::from("PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx"))
+            .unwrap();
+        assert_eq!(r1.key_type, KeyType::R1);
+        assert_eq!(wallet.private_key(&public_key), Ok(&PrivateKey::from(WIF)));
+
+        // rotate the password, then remove a key
+        wallet.set_password("PW5new").unwrap();
+        let mut reloaded = Wallet::from_json(&wallet.to_json()).unwrap();
+        assert_eq!(reloaded.unlock(PASSWORD), Err(WalletError::InvalidPassword));
+        reloaded.unlock("PW5new").unwrap();
+        assert_eq!(reloaded.public_keys().unwrap(), [public_key.clone(), r1.clone()]);
+        assert_eq!(
+            reloaded.remove_key(&r1),
+            Ok(PrivateKey::from("PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx"))
+        );
+        assert_eq!(reloaded.remove_key(&r1), Err(WalletError::KeyNotFound(r1.to_string())));
+
+        reloaded.lock();
+        assert_eq!(reloaded.import_key(PrivateKey::from(WIF)), Err(WalletError::Locked));
+        assert_eq!(reloaded.set_password(PASSWORD), Err(WalletError::Locked));
+        reloaded.unlock("PW5new").unwrap();
+        assert_eq!(reloaded.public_keys().unwrap(), [public_key]);
+    }
+
+    #[test]
+    fn test_file() {
+        let path = std::env::temp_dir().join(format!("antelope-test-{}.wallet", std::process::id()));
+        let wallet = Wallet::new(PASSWORD);
+        wallet.save(&path).unwrap();
+        let mut loaded = Wallet::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        loaded.unlock(PASSWORD).unwrap();
+        assert_eq!(loaded, wallet);
+
+        assert!(matches!(Wallet::load(&path), Err(WalletError::Io(_))));
+        assert!(matches!(Wallet::from_json("{}"), Err(WalletError::BadFile(_))));
+        assert!(matches!(Wallet::from_json(r#"{"cipher_keys":"zz"}"#), Err(WalletError::BadFile(_))));
+        let mut truncated = Wallet::from_json(r#"{"cipher_keys":"2f4c09a49a70c4c8"}"#).unwrap();
+        assert_eq!(truncated.unlock(PASSWORD), Err(WalletError::InvalidPassword));
+    }
+}
@@ -0,0 +1,182 @@
+//! WebAuthn assertions, following `fc::crypto::webauthn` in `nodeos`.
+
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+
+use crate::{sha256, Checksum256, CryptoError, PublicKey, Signature, UserPresence, SIGNATURE_SIZE};
+
+/// The origin scheme required for the relying party
+const REQUIRED_ORIGIN_SCHEME: &str = "https://";
+
+/// The size of the rpid hash, flags and signature counter that start the authenticator data
+const MIN_AUTH_DATA_SIZE: usize = 37;
+
+/// Authenticator data flag set when the user touched the authenticator
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// Authenticator data flag set when the authenticator verified the user
+const FLAG_USER_VERIFIED: u8 = 0x04;
+
+/// base64url, with or without padding, as browsers encode the challenge
+const BASE64URL: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+fn error(msg: &str) -> CryptoError {
+    CryptoError::WebAuthn(msg.to_string())
+}
+
+/**
+ * Recovers the WebAuthn public key that signed `digest`
+ *
+ * The client data must be a `webauthn.get` assertion whose challenge is `digest`, and whose
+ * `https://` origin matches the rpid hash of the authenticator data. The recovered key carries
+ * the rpid and the user presence flags, so it only equals the expected key if both match.
+ */
+pub(crate) fn recover(sig: &Signature, digest: &Checksum256) -> Result<PublicKey, CryptoError> {
+    let auth_data = sig.auth_data().ok_or_else(|| error("bad webauthn signature"))?;
+    let client_json = sig.client_json().ok_or_else(|| error("bad webauthn signature"))?;
+
+    let client: serde_json::Value = serde_json::from_str(&client_json).map_err(|e| CryptoError::WebAuthn(e.to_string()))?;
+    let field = |name: &str| client.get(name).and_then(|v| v.as_str()).unwrap_or_default();
+    if field("type") != "webauthn.get" {
+        return Err(error("webauthn signature type not an assertion"));
+    }
+    let challenge = BASE64URL.decode(field("challenge")).map_err(|_| error("bad webauthn challenge"))?;
+    // the challenge is the digest itself, not hashed again
+    if challenge != digest.data {
+        return Err(error("wrong webauthn challenge"));
+    }
+    let host = field("origin")
+        .strip_prefix(REQUIRED_ORIGIN_SCHEME)
+        .ok_or_else(|| error("webauthn origin must begin with https://"))?;
+    let rpid = host.rsplit_once(':').map_or(host, |(rpid, _port)| rpid);
+
+    if auth_data.len() < MIN_AUTH_DATA_SIZE {
+        return Err(error("auth_data not as large as required"));
+    }
+    if auth_data[..32] != sha256(rpid.as_bytes()).data {
+        return Err(error("webauthn rpid hash doesn't match origin"));
+    }
+    let flags = auth_data[32];
+    let user_presence = if flags & FLAG_USER_VERIFIED != 0 {
+        UserPresence::Verified
+    } else if flags & FLAG_USER_PRESENT != 0 {
+        UserPresence::Present
+    } else {
+        UserPresence::None
+    };
+
+    // the authenticator signs its data followed by the hash of the client data
+    let signed_digest = sha256(&[auth_data.as_slice(), &sha256(client_json.as_bytes()).data].concat());
+    // like `fc::crypto::r1`, both compressed (31..35) and uncompressed (27..31) headers are accepted
+    let header = sig.data[0];
+    if !(27..35).contains(&header) {
+        return Err(CryptoError::BadRecoveryId(header));
+    }
+    let point =
+        crate::ecc::r1::recover(&signed_digest.data, &sig.data[1..SIGNATURE_SIZE], (header - 27) & 3).ok_or(CryptoError::RecoveryFailed)?;
+    PublicKey::webauthn(&point, user_presence, rpid).map_err(|_| CryptoError::RecoveryFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyType, PrivateKey};
+
+    const R1: &str = "PVT_R1_SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2Gk4Tyx";
+
+    /// Signs `digest` the way a browser authenticator does
+    fn assertion(key: &PrivateKey, digest: &Checksum256, origin: &str, rpid: &str, flags: u8, kind: &str) -> Signature {
+        let mut auth_data = sha256(rpid.as_bytes()).data.to_vec();
+        auth_data.push(flags);
+        auth_data.extend_from_slice(&7_u32.to_be_bytes());
+        let challenge = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest.data);
+        let client_json = format!(r#"{{"type":"{}","challenge":"{}","origin":"{}"}}"#, kind, challenge, origin);
+        let signed_digest = sha256(&[auth_data.as_slice(), &sha256(client_json.as_bytes()).data].concat());
+        let compact = key.sign_digest(&signed_digest);
+        Signature::webauthn(&compact.data, &auth_data, &client_json).unwrap()
+    }
+
+    #[test]
+    fn test_recover() {
+        let key = PrivateKey::from(R1);
+        let digest = sha256(b"transaction");
+        let expected = PublicKey::webauthn(&key.public_key().data, UserPresence::Verified, "example.com").unwrap();
+
+        let sig = assertion(&key, &digest, "https://example.com", "example.com", 0x05, "webauthn.get");
+        assert_eq!(sig.key_type, KeyType::WA);
+        assert_eq!(sig.recover_digest(&digest), Ok(expected.clone()));
+        assert!(sig.verify_digest(&digest, &expected));
+        assert!(!sig.verify_digest(&sha256(b"other"), &expected));
+        assert_eq!(Signature::from(sig.to_string().as_str()), sig);
+
+        // nodeos also accepts the uncompressed recovery header, 4 less than the compressed one
+        let mut data = sig.data.clone();
+        data[0] -= 4;
+        let uncompressed = Signature::from_bytes(KeyType::WA, &data).unwrap();
+        assert_eq!(uncompressed.recover_digest(&digest), Ok(expected.clone()));
+
+        // the port is not part of the rpid, and the flags set the user presence of the key
+        let sig = assertion(&key, &digest, "https://example.com:8443", "example.com", 0x01, "webauthn.get");
+        let present = sig.recover_digest(&digest).unwrap();
+        assert_eq!(present.user_presence(), Some(UserPresence::Present));
+        assert_eq!(present.rpid().unwrap(), "example.com");
+        assert!(!sig.verify_digest(&digest, &expected));
+
+        let sig = assertion(&key, &digest, "https://example.com", "example.com", 0x00, "webauthn.get");
+        assert_eq!(sig.recover_digest(&digest).unwrap().user_presence(), Some(UserPresence::None));
+    }
+
+    #[test]
+    fn test_recover_errors() {
+        let key = PrivateKey::from(R1);
+        let digest = sha256(b"transaction");
+        let cases = [
+            (
+                "https://example.com",
+                "example.com",
+                "webauthn.create",
+                "webauthn signature type not an assertion",
+            ),
+            (
+                "http://example.com",
+                "example.com",
+                "webauthn.get",
+                "webauthn origin must begin with https://",
+            ),
+            (
+                "https://example.com",
+                "evil.com",
+                "webauthn.get",
+                "webauthn rpid hash doesn't match origin",
+            ),
+        ];
+        for (origin, rpid, kind, msg) in cases {
+            let sig = assertion(&key, &digest, origin, rpid, 0x05, kind);
+            assert_eq!(sig.recover_digest(&digest), Err(CryptoError::WebAuthn(msg.to_string())));
+        }
+
+        let sig = assertion(&key, &digest, "https://example.com", "example.com", 0x05, "webauthn.get");
+        assert_eq!(
+            sig.recover_digest(&sha256(b"other")),
+            Err(CryptoError::WebAuthn("wrong webauthn challenge".to_string()))
+        );
+
+        let compact = &sig.data[..SIGNATURE_SIZE];
+        let client_json = sig.client_json().unwrap();
+        let short = Signature::webauthn(compact, &sig.auth_data().unwrap()[..36], &client_json).unwrap();
+        assert_eq!(
+            short.recover_digest(&digest),
+            Err(CryptoError::WebAuthn("auth_data not as large as required".to_string()))
+        );
+        let not_json = Signature::webauthn(compact, &sig.auth_data().unwrap(), "{").unwrap();
+        assert!(matches!(not_json.recover_digest(&digest), Err(CryptoError::WebAuthn(_))));
+
+        let mut data = sig.data.clone();
+        data[0] = 26;
+        let sig = Signature::from_bytes(KeyType::WA, &data).unwrap();
+        assert_eq!(sig.recover_digest(&digest), Err(CryptoError::BadRecoveryId(26)));
+    }
+}